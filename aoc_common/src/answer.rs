@@ -0,0 +1,141 @@
+//! The answer to one part of a puzzle, whatever type the day worked it out in
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use num::{BigInt, BigUint};
+
+/// The answer to one part of a day.
+///
+/// Each day returns whichever type suits it (anything that converts into an
+/// `Answer`) so that only the runner has to deal with all of them. Numbers
+/// compare equal by value, whichever variant they are stored in.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    /// For answers that can get too big for 128 bits
+    Big(BigInt),
+    Text(String),
+}
+
+impl Answer {
+    /// The value as a `BigInt` (`None` if it is text)
+    pub fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Answer::Signed(n) => Some((*n).into()),
+            Answer::Unsigned(n) => Some((*n).into()),
+            Answer::Big(n) => Some(n.clone()),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Text(_), _) | (_, Answer::Text(_)) => false,
+            _ => self.to_bigint() == other.to_bigint(),
+        }
+    }
+}
+impl Eq for Answer {}
+
+macro_rules! impl_from_int {
+    ($variant:ident as $wide:ty: $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::$variant(n as $wide)
+            }
+        })*
+    };
+}
+impl_from_int!(Signed as i128: i8, i16, i32, i64, i128, isize);
+impl_from_int!(Unsigned as u128: u8, u16, u32, u64, u128, usize);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        Answer::Big(n)
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Self {
+        Answer::Big(n.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Reads a recorded answer back: anything that is a whole number is a number
+/// (so e.g. `007` equals 7), anything else is text
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<BigInt>() {
+            Ok(n) => Answer::Big(n),
+            Err(_) => Answer::Text(s.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+            Answer::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_like_the_value() {
+        assert_eq!(Answer::from(-3_i32).to_string(), "-3");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        let big = BigUint::from(u128::MAX) * 10_u32;
+        assert_eq!(
+            Answer::from(big).to_string(),
+            "3402823669209384634633746074317682114550"
+        );
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+    }
+
+    #[test]
+    fn numbers_compare_by_value() {
+        assert_eq!(Answer::from(5_u8), Answer::from(5_i64));
+        assert_eq!(Answer::from(5_usize), Answer::from(BigInt::from(5)));
+        assert_ne!(Answer::from(-5_i32), Answer::from(5_u32));
+        assert_ne!(Answer::from(5_u32), Answer::from("5"));
+        assert_eq!(Answer::from("5"), Answer::from("5".to_string()));
+    }
+
+    #[test]
+    fn parse_recorded_answers() {
+        let parse = |s: &str| s.parse::<Answer>().unwrap();
+        assert_eq!(parse("42"), Answer::from(42_u8));
+        assert_eq!(parse("-0"), Answer::from(0_i32));
+        assert_eq!(parse("007"), Answer::from(7_u64));
+        assert_eq!(parse(&u128::MAX.to_string()), Answer::from(u128::MAX));
+        assert_eq!(parse("-17"), Answer::from(BigInt::from(-17)));
+        assert_eq!(parse("ABC"), Answer::from("ABC"));
+        assert_ne!(parse("1.5"), Answer::from(1_u8));
+    }
+}
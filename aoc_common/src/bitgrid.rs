@@ -0,0 +1,353 @@
+//! A grid of bools packed into bits, for maps with only 2 kinds of tiles.
+//!
+//! Whole rows can be compared, combined and counted a word (64 tiles) at a time.
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::geom::{Dirn, Pos};
+use crate::grid::Grid;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A rectangular grid of bools stored row by row, each row as a bitmask of
+/// `u64`s (column `c` is bit `c % 64` of word `c / 64`).
+///
+/// The bits past the width of a row are always 0 so that rows can be
+/// compared and counted directly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// All `false`
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(BITS);
+        Self {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    /// A grid where the bit at `(row, column)` is `f(row, column)`
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> bool) -> Self {
+        let mut grid = Self::new(width, height);
+        for row in 0..height {
+            for col in 0..width {
+                if f(row, col) {
+                    grid.set(Pos::new(row, col), true);
+                }
+            }
+        }
+        grid
+    }
+
+    /// The tiles of `grid` for which `f` is true
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> bool) -> Self {
+        Self::from_fn(grid.width(), grid.height(), |row, col| f(&grid[(row, col)]))
+    }
+
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of rows and columns, for the bounds checks of [`Pos`]
+    pub fn size(&self) -> Pos {
+        Pos::new(self.height, self.width)
+    }
+
+    fn index_of(&self, pos: Pos) -> (usize, u64) {
+        assert!(pos.is_within(self.size()), "{pos:?} is outside the grid");
+        (
+            pos.row * self.words_per_row + pos.col / BITS,
+            1 << (pos.col % BITS),
+        )
+    }
+
+    pub fn get(&self, pos: Pos) -> bool {
+        let (i, bit) = self.index_of(pos);
+        self.words[i] & bit != 0
+    }
+
+    pub fn set(&mut self, pos: Pos, value: bool) {
+        let (i, bit) = self.index_of(pos);
+        if value {
+            self.words[i] |= bit;
+        } else {
+            self.words[i] &= !bit;
+        }
+    }
+
+    pub fn toggle(&mut self, pos: Pos) {
+        let (i, bit) = self.index_of(pos);
+        self.words[i] ^= bit;
+    }
+
+    /// The bitmask of a row
+    pub fn row(&self, row: usize) -> &[u64] {
+        &self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    /// The bitmask of a row, to change it. The bits past the width must be left as 0.
+    pub fn row_mut(&mut self, row: usize) -> &mut [u64] {
+        &mut self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    /// The bitmask of a column (bit `r` is row `r`, like the rows of [`BitGrid::transpose`])
+    pub fn column(&self, col: usize) -> Vec<u64> {
+        let mut mask = vec![0; self.height.div_ceil(BITS)];
+        for row in 0..self.height {
+            if self.get(Pos::new(row, col)) {
+                mask[row / BITS] |= 1 << (row % BITS);
+            }
+        }
+        mask
+    }
+
+    /// Number of `true` tiles
+    pub fn count_ones(&self) -> usize {
+        count_ones(&self.words)
+    }
+
+    /// Number of `true` tiles in a row
+    pub fn row_count_ones(&self, row: usize) -> usize {
+        count_ones(self.row(row))
+    }
+
+    /// Number of columns where rows `a` and `b` are different
+    pub fn count_row_diffs(&self, a: usize, b: usize) -> usize {
+        self.row(a)
+            .iter()
+            .zip(self.row(b))
+            .map(|(x, y)| (x ^ y).count_ones() as usize)
+            .sum()
+    }
+
+    /// Positions of the `true` tiles, row by row
+    pub fn iter_ones(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.height).flat_map(move |row| {
+            self.row(row).iter().enumerate().flat_map(move |(i, word)| {
+                let mut word = *word;
+                std::iter::from_fn(move || {
+                    (word != 0).then(|| {
+                        let bit = word.trailing_zeros() as usize;
+                        word &= word - 1;
+                        Pos::new(row, i * BITS + bit)
+                    })
+                })
+            })
+        })
+    }
+
+    /// Swap the rows and the columns
+    pub fn transpose(&self) -> Self {
+        let mut out = Self::new(self.height, self.width);
+        for pos in self.iter_ones() {
+            out.set(Pos::new(pos.col, pos.row), true);
+        }
+        out
+    }
+
+    /// Move every tile one step in `dirn`, the ones that fall off the edge are lost
+    pub fn shift(&self, dirn: Dirn) -> Self {
+        let mut out = Self::new(self.width, self.height);
+        let n = self.words_per_row;
+        match dirn {
+            Dirn::N if self.height > 0 => {
+                out.words[..(self.height - 1) * n].copy_from_slice(&self.words[n..])
+            }
+            Dirn::S if self.height > 0 => {
+                out.words[n..].copy_from_slice(&self.words[..(self.height - 1) * n])
+            }
+            Dirn::N | Dirn::S => {}
+            Dirn::E => {
+                for row in 0..self.height {
+                    let (src, dest) = (self.row(row), out.row_mut(row));
+                    let mut carry = 0;
+                    for (d, s) in dest.iter_mut().zip(src) {
+                        *d = (s << 1) | carry;
+                        carry = s >> (BITS - 1);
+                    }
+                }
+                out.clear_past_width();
+            }
+            Dirn::W => {
+                for row in 0..self.height {
+                    let (src, dest) = (self.row(row), out.row_mut(row));
+                    let mut carry = 0;
+                    for (d, s) in dest.iter_mut().zip(src).rev() {
+                        *d = (s >> 1) | carry;
+                        carry = s << (BITS - 1);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// The tiles that are in `self` but not in `other`
+    pub fn and_not(&self, other: &BitGrid) -> Self {
+        self.zip_words(other, |a, b| a & !b)
+    }
+
+    fn zip_words(&self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) -> Self {
+        assert_eq!(
+            self.size(),
+            other.size(),
+            "the grids should be the same size"
+        );
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| f(*a, *b))
+            .collect();
+        Self { words, ..*self }
+    }
+
+    fn clear_past_width(&mut self) {
+        if !self.width.is_multiple_of(BITS) {
+            let mask = (1 << (self.width % BITS)) - 1;
+            for row in 0..self.height {
+                *self
+                    .row_mut(row)
+                    .last_mut()
+                    .expect("a row has at least 1 word") &= mask;
+            }
+        }
+    }
+}
+
+fn count_ones(words: &[u64]) -> usize {
+    words.iter().map(|w| w.count_ones() as usize).sum()
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: &BitGrid) -> BitGrid {
+        self.zip_words(rhs, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: &BitGrid) -> BitGrid {
+        self.zip_words(rhs, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: &BitGrid) -> BitGrid {
+        self.zip_words(rhs, |a, b| a ^ b)
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut out = BitGrid {
+            words: self.words.iter().map(|w| !w).collect(),
+            ..*self
+        };
+        out.clear_past_width();
+        out
+    }
+}
+
+/// Prints it with `#` for `true` and `.` for `false`
+impl fmt::Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.height {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for col in 0..self.width {
+                let c = if self.get(Pos::new(row, col)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(s: &str) -> BitGrid {
+        let lines: Vec<&[u8]> = s.lines().map(str::as_bytes).collect();
+        BitGrid::from_fn(lines[0].len(), lines.len(), |row, col| {
+            lines[row][col] == b'#'
+        })
+    }
+
+    #[test]
+    fn get_set_and_count() {
+        let mut g = bits("#..\n.#.\n");
+        assert!(g.get(Pos::new(0, 0)) && !g.get(Pos::new(1, 2)));
+        g.set(Pos::new(1, 2), true);
+        g.toggle(Pos::new(0, 0));
+        assert_eq!(g.to_string(), "...\n.##");
+        assert_eq!((g.count_ones(), g.row_count_ones(0)), (2, 0));
+        assert_eq!(g.count_row_diffs(0, 1), 2);
+        assert_eq!(g.column(2), [0b10]);
+        assert_eq!(
+            g.iter_ones().collect::<Vec<_>>(),
+            [Pos::new(1, 1), Pos::new(1, 2)]
+        );
+    }
+
+    #[test]
+    fn wide_rows() {
+        let mut g = BitGrid::new(130, 2);
+        for col in [0, 63, 64, 129] {
+            g.set(Pos::new(1, col), true);
+        }
+        assert_eq!(g.row(1).len(), 3);
+        assert_eq!(
+            g.iter_ones().map(|p| p.col).collect::<Vec<_>>(),
+            [0, 63, 64, 129]
+        );
+        let east: Vec<_> = g.shift(Dirn::E).iter_ones().map(|p| p.col).collect();
+        assert_eq!(east, [1, 64, 65]);
+        let west: Vec<_> = g.shift(Dirn::W).iter_ones().map(|p| p.col).collect();
+        assert_eq!(west, [62, 63, 128]);
+        assert_eq!((!&g).count_ones(), 2 * 130 - 4);
+        assert_eq!(g.transpose().transpose(), g);
+    }
+
+    #[test]
+    fn shifts_and_logic() {
+        let g = bits("#.\n.#\n");
+        assert_eq!(g.shift(Dirn::N), bits(".#\n..\n"));
+        assert_eq!(g.shift(Dirn::S), bits("..\n#.\n"));
+        assert_eq!(g.shift(Dirn::E), bits(".#\n..\n"));
+        assert_eq!(g.shift(Dirn::W), bits("..\n#.\n"));
+        let h = bits("##\n..\n");
+        assert_eq!(&g & &h, bits("#.\n..\n"));
+        assert_eq!(&g | &h, bits("##\n.#\n"));
+        assert_eq!(&g ^ &h, bits(".#\n.#\n"));
+        assert_eq!(g.and_not(&h), bits("..\n.#\n"));
+        assert_eq!(g.transpose(), g);
+    }
+}
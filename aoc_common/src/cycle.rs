@@ -0,0 +1,185 @@
+//! Finding where an iteration starts repeating, e.g. to skip to the end of a
+//! simulation that runs for far too many steps to do one at a time
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter;
+
+/// The states `x0, f(x0), f(f(x0)), ...` of an iteration that ends up going round a cycle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// Number of states before the cycle (so it starts at `states[prefix_len]`)
+    pub prefix_len: usize,
+    /// Number of states in the cycle
+    pub cycle_len: usize,
+    /// The states before the cycle and then once round it,
+    /// i.e. state `n` for every `n < prefix_len + cycle_len`
+    pub states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    fn record(start: S, prefix_len: usize, cycle_len: usize, mut f: impl FnMut(&S) -> S) -> Self {
+        let states = iter::successors(Some(start), |s| Some(f(s)))
+            .take(prefix_len + cycle_len)
+            .collect();
+        Self {
+            prefix_len,
+            cycle_len,
+            states,
+        }
+    }
+
+    /// Index into `states` of the state that is the same as state `n`
+    pub fn index_of(&self, n: u64) -> usize {
+        let prefix_len = self.prefix_len as u64;
+        if n < prefix_len {
+            n as usize
+        } else {
+            self.prefix_len + ((n - prefix_len) % self.cycle_len as u64) as usize
+        }
+    }
+
+    /// State `n` (the start being state 0), however big `n` is
+    pub fn nth(&self, n: u64) -> &S {
+        &self.states[self.index_of(n)]
+    }
+}
+
+/// Remembers every state so it can stop as soon as one repeats
+/// (so `f` is only called once per state).
+///
+/// Never returns if the states don't repeat, this goes for all the `find_cycle_*` functions.
+pub fn find_cycle_hashed<S: Clone + Eq + Hash>(start: S, mut f: impl FnMut(&S) -> S) -> Cycle<S> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        if let Some(&first) = seen.get(&state) {
+            return Cycle {
+                prefix_len: first,
+                cycle_len: states.len() - first,
+                states,
+            };
+        }
+        seen.insert(state.clone(), states.len());
+        let next = f(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// Floyd's tortoise and hare: only keeps 2 states while searching, but calls
+/// `f` several times per state
+pub fn find_cycle_floyd<S: Clone + Eq>(start: S, mut f: impl FnMut(&S) -> S) -> Cycle<S> {
+    // the hare goes twice as fast so they meet after a multiple of the cycle length
+    let mut tortoise = f(&start);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        let step = f(&hare);
+        hare = f(&step);
+    }
+    // so with the hare that far ahead, they meet again at the start of the cycle
+    let mut prefix_len = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix_len += 1;
+    }
+    let mut cycle_len = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        cycle_len += 1;
+    }
+    Cycle::record(start, prefix_len, cycle_len, f)
+}
+
+/// Brent's algorithm: like [`find_cycle_floyd`] but usually calls `f` fewer times
+pub fn find_cycle_brent<S: Clone + Eq>(start: S, mut f: impl FnMut(&S) -> S) -> Cycle<S> {
+    // the tortoise waits at each power of 2 until the hare comes back round to it
+    let mut power = 1;
+    let mut cycle_len = 1;
+    let mut tortoise = start.clone();
+    let mut hare = f(&start);
+    while tortoise != hare {
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+        hare = f(&hare);
+        cycle_len += 1;
+    }
+    // with the hare a whole cycle ahead, they first meet at the start of the cycle
+    let mut prefix_len = 0;
+    tortoise = start.clone();
+    hare = (0..cycle_len).fold(start.clone(), |s, _| f(&s));
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix_len += 1;
+    }
+    Cycle::record(start, prefix_len, cycle_len, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every function from 0..N to itself, as a table
+    fn all_functions(n: usize) -> impl Iterator<Item = Vec<usize>> {
+        (0..n.pow(n as u32)).map(move |mut i| {
+            (0..n)
+                .map(|_| {
+                    let x = i % n;
+                    i /= n;
+                    x
+                })
+                .collect()
+        })
+    }
+
+    fn naive(start: usize, table: &[usize]) -> (usize, usize) {
+        let mut states = vec![start];
+        loop {
+            let next = table[*states.last().unwrap()];
+            if let Some(first) = states.iter().position(|s| *s == next) {
+                return (first, states.len() - first);
+            }
+            states.push(next);
+        }
+    }
+
+    #[test]
+    fn all_methods_agree_with_naive() {
+        for table in all_functions(5) {
+            for start in 0..5 {
+                let f = |x: &usize| table[*x];
+                let expected = naive(start, &table);
+                for cycle in [
+                    find_cycle_hashed(start, f),
+                    find_cycle_floyd(start, f),
+                    find_cycle_brent(start, f),
+                ] {
+                    assert_eq!((cycle.prefix_len, cycle.cycle_len), expected, "{table:?}");
+                    let mut state = start;
+                    for n in 0..20 {
+                        assert_eq!(*cycle.nth(n), state);
+                        state = table[state];
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn jump_far_ahead() {
+        // 3 states before a cycle of 7
+        let f = |x: &u64| if *x < 3 { x + 1 } else { 3 + (x - 2) % 7 };
+        let cycle = find_cycle_brent(0, f);
+        assert_eq!((cycle.prefix_len, cycle.cycle_len), (3, 7));
+        assert_eq!(*cycle.nth(1_000_000_000), 3 + (1_000_000_000 - 3) % 7);
+        assert_eq!(cycle.states, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+}
@@ -0,0 +1,203 @@
+use std::error::Error;
+use std::fmt;
+use std::process;
+
+/// An error in the format of the input, pointing at the offending text.
+///
+/// Parsers create it with [`ParseError::new`] from a slice of the text they
+/// were given and the top-level `parse` function of each day calls
+/// [`ParseError::locate`] on it to fill in where in the input that slice is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number (0 if it couldn't be located)
+    pub line: usize,
+    /// 1-based column (in chars) of the start of `found`
+    pub column: usize,
+    /// The whole line that contains the error
+    pub line_text: String,
+    /// The offending text
+    pub found: String,
+    /// What it should've been instead
+    pub expected: String,
+    /// Address of `found` in the original input, only used by `locate`
+    found_addr: usize,
+}
+
+impl ParseError {
+    /// `found` should be a slice of the input being parsed (not a copy of it)
+    /// so that it can be found again by [`ParseError::locate`].
+    pub fn new(found: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line: 0,
+            column: 0,
+            line_text: String::new(),
+            found: found.to_string(),
+            expected: expected.into(),
+            found_addr: found.as_ptr() as usize,
+        }
+    }
+
+    /// Error at the very end of `input` (e.g. a required line is missing)
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        Self::new(&input[input.len()..], expected).locate(input)
+    }
+
+    /// Fill in the line and column using the position of `found` in `input`.
+    /// Does nothing if the error was already located or if `found` isn't in `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.line != 0 || !(start..=start + input.len()).contains(&self.found_addr) {
+            return self;
+        }
+        let offset = self.found_addr - start;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        self.line = input[..offset].matches('\n').count() + 1;
+        self.column = input[line_start..offset].chars().count() + 1;
+        self.line_text = input[line_start..line_end]
+            .trim_end_matches('\r')
+            .to_string();
+        self
+    }
+
+    /// Multi-line message with the offending line and a caret under the bad text
+    pub fn render(&self) -> String {
+        if self.line == 0 {
+            return self.to_string();
+        }
+        let line_no = self.line.to_string();
+        let pad = " ".repeat(line_no.len());
+        let n_carets = self
+            .found
+            .lines()
+            .next()
+            .unwrap_or("")
+            .chars()
+            .count()
+            .max(1);
+        format!(
+            "{self}\n{pad} |\n{line_no} | {}\n{pad} | {}{}",
+            self.line_text,
+            " ".repeat(self.column - 1),
+            "^".repeat(n_carets)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line != 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        write!(f, "expected {}, ", self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The input is in the right format but the puzzle has no answer for it,
+/// e.g. the path never reaches the end or the answer doesn't fit in its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// The answer (or a number needed to get it) is too big for its type
+    pub fn overflow() -> Self {
+        Self::new("the answer is too big")
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+/// Unwrap the result of parsing a day's input, or print the error and exit
+pub fn unwrap_or_exit<T>(result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("error: {}", e.render());
+        process::exit(1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Game 1: 3 blue\nGame 2: 4 purple, 1 red\n";
+
+    #[test]
+    fn locate_finds_line_and_column() {
+        let line2 = INPUT.lines().nth(1).unwrap();
+        let e = ParseError::new(&line2[10..16], "a color").locate(INPUT);
+        assert_eq!((e.line, e.column), (2, 11));
+        assert_eq!(e.line_text, "Game 2: 4 purple, 1 red");
+        assert_eq!(e.found, "purple");
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 11: expected a color, found \"purple\""
+        );
+    }
+
+    #[test]
+    fn render_points_at_the_bad_text() {
+        let line2 = INPUT.lines().nth(1).unwrap();
+        let e = ParseError::new(&line2[10..16], "a color").locate(INPUT);
+        assert_eq!(
+            e.render(),
+            "line 2, column 11: expected a color, found \"purple\"\n  \
+             |\n2 | Game 2: 4 purple, 1 red\n  |           ^^^^^^"
+        );
+    }
+
+    #[test]
+    fn unrelated_text_is_not_located() {
+        let copy = String::from("purple");
+        let e = ParseError::new(&copy, "a color").locate(INPUT);
+        assert_eq!(e.line, 0);
+        assert_eq!(e.render(), "expected a color, found \"purple\"");
+    }
+
+    #[test]
+    fn locate_keeps_first_location() {
+        let e = ParseError::new(&INPUT[0..4], "x").locate(INPUT);
+        let inner = &INPUT[15..];
+        assert_eq!(e.clone().locate(inner), e);
+    }
+
+    #[test]
+    fn columns_count_chars_and_crlf_is_trimmed() {
+        let input = "ab\r\néé x\r\n";
+        let e = ParseError::new(&input[9..10], "y").locate(input);
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.line_text, "éé x");
+    }
+
+    #[test]
+    fn at_end_of_input() {
+        let e = ParseError::at_end("Time: 7\n", "a 'Distance:' line");
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 1: expected a 'Distance:' line, found nothing"
+        );
+    }
+}
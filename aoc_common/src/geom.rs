@@ -0,0 +1,242 @@
+//! Positions and directions on a grid, e.g. for walking around a map
+use std::ops::{Add, Neg, Sub};
+
+/// One of the 4 directions (north is up, i.e. towards row 0)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dirn {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dirn {
+    /// Clockwise from north
+    pub const ALL: [Dirn; 4] = [Dirn::N, Dirn::E, Dirn::S, Dirn::W];
+
+    pub fn opp(self) -> Self {
+        use Dirn::*;
+        match self {
+            N => S,
+            S => N,
+            E => W,
+            W => E,
+        }
+    }
+
+    /// Turn 90 degrees anticlockwise
+    pub fn turn_left(self) -> Self {
+        use Dirn::*;
+        match self {
+            N => W,
+            W => S,
+            S => E,
+            E => N,
+        }
+    }
+
+    /// Turn 90 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opp()
+    }
+
+    /// The step to take to move one tile in this direction
+    pub fn offset(self) -> IPos {
+        match self {
+            Dirn::N => IPos::new(-1, 0),
+            Dirn::E => IPos::new(0, 1),
+            Dirn::S => IPos::new(1, 0),
+            Dirn::W => IPos::new(0, -1),
+        }
+    }
+}
+
+/// Offsets to the 8 surrounding tiles, clockwise from north
+const OFFSETS_8: [(i64, i64); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A position inside a grid: `row` counts down from the top and `col` right
+/// from the left, both from 0.
+///
+/// The bounds checked methods take the size of the grid as a `Pos` too
+/// (the number of rows and columns, i.e. just past the last tile).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn is_within(self, size: Pos) -> bool {
+        self.row < size.row && self.col < size.col
+    }
+
+    /// One tile in `dirn`, if that is still inside a grid of `size`
+    pub fn step(self, dirn: Dirn, size: Pos) -> Option<Self> {
+        (self.to_signed() + dirn.offset()).to_unsigned_within(size)
+    }
+
+    /// The (up to) 4 tiles next to this one inside a grid of `size`,
+    /// with the direction to each of them
+    pub fn neighbours4(self, size: Pos) -> impl Iterator<Item = (Dirn, Pos)> {
+        Dirn::ALL
+            .into_iter()
+            .filter_map(move |d| Some((d, self.step(d, size)?)))
+    }
+
+    /// The (up to) 8 tiles around this one (including diagonally) inside a grid of `size`
+    pub fn neighbours8(self, size: Pos) -> impl Iterator<Item = Pos> {
+        self.to_signed()
+            .neighbours8()
+            .filter_map(move |p| p.to_unsigned_within(size))
+    }
+
+    pub fn manhattan_dist(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// Number of king moves (i.e. including diagonally) from one to the other
+    pub fn chebyshev_dist(self, other: Pos) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+
+    pub fn to_signed(self) -> IPos {
+        let signed = |n: usize| i64::try_from(n).expect("grid positions should fit in an i64");
+        IPos::new(signed(self.row), signed(self.col))
+    }
+
+    /// Where it is in the input (1-based line and column), for error messages
+    pub fn describe(self) -> String {
+        format!("line {}, column {}", self.row + 1, self.col + 1)
+    }
+}
+
+/// A position that can be outside of a grid (or an offset between positions)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IPos {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl IPos {
+    pub fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    pub fn step(self, dirn: Dirn) -> Self {
+        self + dirn.offset()
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = (Dirn, IPos)> {
+        Dirn::ALL.into_iter().map(move |d| (d, self.step(d)))
+    }
+
+    /// The 8 positions around this one (including diagonally)
+    pub fn neighbours8(self) -> impl Iterator<Item = IPos> {
+        OFFSETS_8
+            .into_iter()
+            .map(move |(row, col)| self + IPos::new(row, col))
+    }
+
+    pub fn manhattan_dist(self, other: IPos) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// Number of king moves (i.e. including diagonally) from one to the other
+    pub fn chebyshev_dist(self, other: IPos) -> u64 {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+
+    /// `None` if it is above or left of the grid
+    pub fn to_unsigned(self) -> Option<Pos> {
+        Some(Pos::new(
+            self.row.try_into().ok()?,
+            self.col.try_into().ok()?,
+        ))
+    }
+
+    /// `None` if it is outside a grid of `size`
+    pub fn to_unsigned_within(self, size: Pos) -> Option<Pos> {
+        self.to_unsigned().filter(|p| p.is_within(size))
+    }
+}
+
+impl Add for IPos {
+    type Output = IPos;
+
+    fn add(self, rhs: IPos) -> IPos {
+        IPos::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub for IPos {
+    type Output = IPos;
+
+    fn sub(self, rhs: IPos) -> IPos {
+        IPos::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Neg for IPos {
+    type Output = IPos;
+
+    fn neg(self) -> IPos {
+        IPos::new(-self.row, -self.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for d in Dirn::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.opp());
+            assert_eq!(d.offset() + d.opp().offset(), IPos::default());
+        }
+        assert_eq!(Dirn::N.turn_right(), Dirn::E);
+        assert_eq!(Dirn::N.turn_left(), Dirn::W);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let size = Pos::new(2, 3);
+        let corner: Vec<_> = Pos::new(0, 0).neighbours4(size).collect();
+        assert_eq!(
+            corner,
+            [(Dirn::E, Pos::new(0, 1)), (Dirn::S, Pos::new(1, 0))]
+        );
+        assert_eq!(Pos::new(0, 1).neighbours8(size).count(), 5);
+        assert_eq!(Pos::new(1, 2).step(Dirn::E, size), None);
+        assert_eq!(Pos::new(1, 2).step(Dirn::N, size), Some(Pos::new(0, 2)));
+        assert_eq!(IPos::new(0, 0).neighbours8().count(), 8);
+        assert_eq!(IPos::new(-1, 2).to_unsigned(), None);
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Pos::new(1, 6), Pos::new(5, 4));
+        assert_eq!((a.manhattan_dist(b), a.chebyshev_dist(b)), (6, 4));
+        let (a, b) = (IPos::new(-3, 2), IPos::new(1, -1));
+        assert_eq!((a.manhattan_dist(b), a.chebyshev_dist(b)), (7, 4));
+        assert_eq!(b - a, IPos::new(4, -3));
+    }
+}
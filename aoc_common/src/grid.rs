@@ -0,0 +1,302 @@
+//! A rectangular grid of tiles, e.g. a map where each char of the input is a tile
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::geom::Pos;
+use crate::parse::parse_grid;
+
+/// A tile that is written as a single char in the input
+pub trait FromChar: Sized {
+    /// Describes the valid chars for error messages, e.g. `"'.' or '#'"`
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+}
+
+/// A tile that can be printed as a single char (usually the inverse of [`FromChar`])
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+///
+/// Tiles are indexed by `(row, column)` or a [`Pos`], starting from the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid where the tile at `(row, column)` is `f(row, column)`
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let tiles = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| f(row, col))
+            .collect();
+        Self::from_tiles(width, height, tiles)
+    }
+
+    /// # Panics
+    /// If the rows aren't all the same length
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Self {
+        let mut tiles = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            let width = *width.get_or_insert(row.len());
+            assert_eq!(
+                row.len(),
+                width,
+                "the rows of a grid should be the same length"
+            );
+            tiles.extend(row);
+            height += 1;
+        }
+        Self::from_tiles(width.unwrap_or(0), height, tiles)
+    }
+
+    fn from_tiles(width: usize, height: usize, tiles: Vec<T>) -> Self {
+        debug_assert_eq!(tiles.len(), width * height);
+        // a grid with no columns has no rows either, so that `rows()` works
+        let height = if width == 0 { 0 } else { height };
+        Self {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of rows and columns, for the bounds checks of [`Pos`]
+    pub fn size(&self) -> Pos {
+        Pos::new(self.height, self.width)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.tiles[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.tiles[row * self.width + col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.tiles[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.tiles.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} is outside the grid");
+        self.tiles.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// All the tiles, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.tiles.iter()
+    }
+
+    /// All the tiles, row by row, with their positions
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(move |(i, t)| (Pos::new(i / width, i % width), t))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_tiles(
+            self.width,
+            self.height,
+            self.tiles.iter().map(&mut f).collect(),
+        )
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, tile: T) -> Self {
+        Self::from_tiles(width, height, vec![tile; width * height])
+    }
+
+    /// Swap the rows and the columns
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |row, col| self[(col, row)].clone())
+    }
+
+    /// Rotate clockwise by 90 degrees, so the top row becomes the right column
+    pub fn rotate_cw(&self) -> Self {
+        let h = self.height;
+        Self::from_fn(h, self.width, |row, col| self[(h - 1 - col, row)].clone())
+    }
+
+    /// Rotate anticlockwise by 90 degrees, so the top row becomes the left column
+    pub fn rotate_ccw(&self) -> Self {
+        let w = self.width;
+        Self::from_fn(self.height, w, |row, col| self[(col, w - 1 - row)].clone())
+    }
+
+    /// Mirror left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let w = self.width;
+        Self::from_fn(w, self.height, |row, col| self[(row, w - 1 - col)].clone())
+    }
+
+    /// Mirror top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let h = self.height;
+        Self::from_fn(self.width, h, |row, col| self[(h - 1 - row, col)].clone())
+    }
+}
+
+impl<T: FromChar> Grid<T> {
+    /// Parse `lines` with one tile per char, see [`parse_grid`]
+    pub fn parse<'a>(lines: impl IntoIterator<Item = &'a str>) -> Result<Self, ParseError> {
+        Ok(Self::from_rows(parse_grid(
+            lines,
+            T::from_char,
+            T::EXPECTED,
+        )?))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside the grid"))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self[(pos.row, pos.col)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        &mut self[(pos.row, pos.col)]
+    }
+}
+
+/// Prints it like the input: one line per row
+impl<T: ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|t| write!(f, "{}", t.to_char()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl FromChar for bool {
+        const EXPECTED: &'static str = "'.' or '#'";
+
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(false),
+                '#' => Some(true),
+                _ => None,
+            }
+        }
+    }
+    impl ToChar for bool {
+        fn to_char(&self) -> char {
+            if *self {
+                '#'
+            } else {
+                '.'
+            }
+        }
+    }
+
+    fn grid(s: &str) -> Grid<bool> {
+        Grid::parse(s.lines()).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let g = grid("#..\n.#.\n");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert!(g[(0, 0)] && g[(1, 1)] && !g[(1, 2)]);
+        assert_eq!(g.get(2, 0), None);
+        assert_eq!(g.to_string(), "#..\n.#.");
+        let e = Grid::<bool>::parse(["#.", "x."]).unwrap_err();
+        assert_eq!(e.expected, "'.' or '#'");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g = grid("#..\n.##\n");
+        assert_eq!(
+            g.rows().collect::<Vec<_>>(),
+            [[true, false, false], [false, true, true]]
+        );
+        let cols: Vec<Vec<bool>> = g.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(cols, [[true, false], [false, true], [false, true]]);
+        assert_eq!(
+            g.indexed_iter()
+                .filter(|(_, t)| **t)
+                .map(|(pos, _)| (pos.row, pos.col))
+                .collect::<Vec<_>>(),
+            [(0, 0), (1, 1), (1, 2)]
+        );
+        assert!(g[Pos::new(1, 2)] && g.size() == Pos::new(2, 3));
+        assert_eq!(Grid::<bool>::from_rows([]).rows().count(), 0);
+    }
+
+    #[test]
+    fn transformations() {
+        let g = grid("##.\n...\n");
+        assert_eq!(g.transpose(), grid("#.\n#.\n..\n"));
+        assert_eq!(g.rotate_cw(), grid(".#\n.#\n..\n"));
+        assert_eq!(g.rotate_ccw(), grid("..\n#.\n#.\n"));
+        assert_eq!(g.flip_horizontal(), grid(".##\n...\n"));
+        assert_eq!(g.flip_vertical(), grid("...\n##.\n"));
+        assert_eq!(
+            g.rotate_cw().rotate_cw(),
+            g.flip_horizontal().flip_vertical()
+        );
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+    }
+}
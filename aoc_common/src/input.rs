@@ -0,0 +1,196 @@
+//! Where a day's input text comes from: its `src/input.txt`, another file,
+//! stdin or one of the example files next to the input.
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::trace;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `src/input.txt` of the day's crate
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+    /// One of the day's `src/example*.txt` files, see [`example_path`]
+    Example(String),
+}
+
+pub const USAGE: &str = concat!(
+    "  --input PATH, -i PATH    read the input from PATH (`-` for stdin)\n",
+    "  --example NAME, -e NAME  use one of the example files, e.g. `1_2` for src/example_1_2.txt\n",
+    "  -                        read the input from stdin",
+);
+
+impl InputSource {
+    /// Parse one input-selecting argument. `arg` is the current argument and
+    /// `next` is used to get its value if it needs one.
+    /// Returns `Ok(None)` if `arg` isn't an input argument.
+    pub fn parse_arg(
+        arg: &str,
+        next: impl FnOnce() -> Option<String>,
+    ) -> Result<Option<Self>, String> {
+        let value = match arg {
+            "--input" | "-i" | "--example" | "-e" => {
+                Some(next().ok_or_else(|| format!("{arg} requires a value"))?)
+            }
+            _ => None,
+        };
+        Ok(match (arg, value) {
+            ("-", _) => Some(Self::Stdin),
+            ("--input" | "-i", Some(path)) if path == "-" => Some(Self::Stdin),
+            ("--input" | "-i", Some(path)) => Some(Self::File(path.into())),
+            ("--example" | "-e", Some(name)) => Some(Self::Example(name)),
+            _ => None,
+        })
+    }
+
+    /// Parse the arguments of a day's binary (only the input options are accepted)
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        let mut source = Self::Default;
+        while let Some(arg) = args.next() {
+            source = Self::parse_arg(&arg, || args.next())?
+                .ok_or_else(|| format!("unknown argument: {arg:?}"))?;
+        }
+        Ok(source)
+    }
+
+    /// The path this reads from (`None` for stdin)
+    pub fn path(&self, crate_dir: &Path) -> io::Result<Option<PathBuf>> {
+        Ok(match self {
+            Self::Default => Some(crate_dir.join("src").join("input.txt")),
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+            Self::Example(name) => Some(example_path(crate_dir, name).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!(
+                        "no example called {name:?} (available: {})",
+                        list_examples(crate_dir).join(", ")
+                    ),
+                )
+            })?),
+        })
+    }
+
+    pub fn read(&self, crate_dir: &Path) -> io::Result<String> {
+        match self.path(crate_dir)? {
+            Some(path) => fs::read_to_string(&path).map_err(|e| {
+                io::Error::new(e.kind(), format!("couldn't read {}: {e}", path.display()))
+            }),
+            None => {
+                let mut s = String::new();
+                io::stdin().read_to_string(&mut s)?;
+                Ok(s)
+            }
+        }
+    }
+}
+
+/// Find an example file of the crate in `crate_dir`. `name` can be the full
+/// file name (`example_1_2.txt`), the name without the extension (`example_1_2`)
+/// or just the suffix (`1_2`).
+pub fn example_path(crate_dir: &Path, name: &str) -> Option<PathBuf> {
+    let src = crate_dir.join("src");
+    [
+        name.to_string(),
+        format!("{name}.txt"),
+        format!("example_{name}.txt"),
+    ]
+    .into_iter()
+    .filter(|f| f.starts_with("example"))
+    .map(|f| src.join(f))
+    .find(|p| p.is_file())
+}
+
+/// Names (without the `.txt`) of all the example files of the crate in `crate_dir`
+pub fn list_examples(crate_dir: &Path) -> Vec<String> {
+    let mut names: Vec<_> = fs::read_dir(crate_dir.join("src"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".txt")?;
+            stem.starts_with("example").then(|| stem.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Read the input selected by the command-line arguments of a day's binary,
+/// exiting with an error message if that fails
+pub fn read_input_from_args(crate_dir: &str) -> String {
+    let mut args = std::env::args();
+    let prog = args.next().unwrap_or_default();
+    let mut verbosity = 0_u8;
+    let args = args.filter(|arg| match trace::parse_verbosity_arg(arg) {
+        Some(n) => {
+            verbosity = verbosity.saturating_add(n);
+            false
+        }
+        None => true,
+    });
+    let source = InputSource::from_args(args).unwrap_or_else(|msg| {
+        eprintln!(
+            "error: {msg}\n\nusage: {prog} [-v | -vv] [INPUT OPTIONS]\n{}\n{USAGE}",
+            trace::USAGE
+        );
+        process::exit(2)
+    });
+    let input = source.read(Path::new(crate_dir)).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(1)
+    });
+    trace::set_verbosity(verbosity);
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<InputSource, String> {
+        InputSource::from_args(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parse_input_args() {
+        assert_eq!(parse(""), Ok(InputSource::Default));
+        assert_eq!(parse("-"), Ok(InputSource::Stdin));
+        assert_eq!(parse("--input -"), Ok(InputSource::Stdin));
+        assert_eq!(parse("-i a/b.txt"), Ok(InputSource::File("a/b.txt".into())));
+        assert_eq!(
+            parse("--example 1_2"),
+            Ok(InputSource::Example("1_2".into()))
+        );
+        assert!(parse("--input").is_err());
+        assert!(parse("--bad").is_err());
+    }
+
+    #[test]
+    fn example_names() {
+        let dir = std::env::temp_dir().join(format!("aoc_common_examples_{}", process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        for f in ["example.txt", "example_1_2.txt", "input.txt"] {
+            fs::write(dir.join("src").join(f), "").unwrap();
+        }
+        assert_eq!(list_examples(&dir), ["example", "example_1_2"]);
+        let expected = dir.join("src").join("example_1_2.txt");
+        assert_eq!(example_path(&dir, "1_2"), Some(expected.clone()));
+        assert_eq!(example_path(&dir, "example_1_2"), Some(expected.clone()));
+        assert_eq!(example_path(&dir, "example_1_2.txt"), Some(expected));
+        assert_eq!(
+            example_path(&dir, "example"),
+            Some(dir.join("src").join("example.txt"))
+        );
+        assert_eq!(example_path(&dir, "input"), None);
+        assert_eq!(example_path(&dir, "3"), None);
+        let err = InputSource::Example("3".into()).read(&dir).unwrap_err();
+        assert!(err.to_string().contains("example, example_1_2"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
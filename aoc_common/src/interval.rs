@@ -0,0 +1,359 @@
+//! Ranges of integers and sets of them, e.g. for mapping whole ranges of numbers at once
+use std::ops::{Add, Sub};
+
+/// The integer types that an [`Interval`] can be made of
+pub trait Int: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + From<u8> {}
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>> Int for T {}
+
+/// The half-open range `start..end`, empty if `start >= end`.
+///
+/// Empty intervals only compare equal if their bounds are, so use
+/// [`Interval::is_empty`] to check for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Int> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// `first..=last`
+    pub fn from_incl(first: T, last: T) -> Self {
+        Self::new(first, last + T::from(1))
+    }
+
+    /// `len` numbers starting at `start`
+    pub fn from_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Number of integers in it
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::from(0)
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// The largest number in it
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.end - T::from(1))
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    /// Whether every number in `other` is also in `self`
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The numbers in both (possibly empty)
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The numbers in `self` but not in `other`, as the parts before and after
+    /// `other` (either or both can be empty)
+    pub fn difference(&self, other: &Self) -> (Self, Self) {
+        (
+            Self::new(self.start, self.end.min(other.start)),
+            Self::new(self.start.max(other.end), self.end),
+        )
+    }
+
+    /// The parts below `x` and from `x` onwards (either can be empty)
+    pub fn split_at(&self, x: T) -> (Self, Self) {
+        if self.is_empty() {
+            return (*self, *self);
+        }
+        let mid = x.clamp(self.start, self.end);
+        (Self::new(self.start, mid), Self::new(mid, self.end))
+    }
+
+    /// Move it so that `from` ends up at `to` (i.e. add `to - from` to both ends)
+    pub fn translate(&self, from: T, to: T) -> Self {
+        let add = |x: T| {
+            if to >= from {
+                x + (to - from)
+            } else {
+                x - (from - to)
+            }
+        };
+        Self::new(add(self.start), add(self.end))
+    }
+}
+
+/// A set of integers stored as its maximal intervals, in order.
+///
+/// The intervals are kept disjoint, non-empty and never adjacent (adjacent
+/// ones are coalesced) so two sets are equal exactly when they have the same numbers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Int> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// The maximal intervals, in order
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Interval<T>> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in it
+    pub fn len(&self) -> T {
+        self.iter().fold(T::from(0), |n, iv| n + iv.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|iv| iv.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().and_then(Interval::last)
+    }
+
+    /// Index of the first interval that ends after `x`
+    fn first_ending_after(&self, x: T) -> usize {
+        self.intervals.partition_point(|iv| iv.end <= x)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.intervals
+            .get(self.first_ending_after(x))
+            .is_some_and(|iv| iv.start <= x)
+    }
+
+    /// Whether every number in `iv` is in the set
+    pub fn contains_interval(&self, iv: &Interval<T>) -> bool {
+        iv.is_empty()
+            || self
+                .intervals
+                .get(self.first_ending_after(iv.start))
+                .is_some_and(|found| found.contains_interval(iv))
+    }
+
+    /// Add the numbers in `iv`, merging it with the intervals it overlaps or touches
+    pub fn insert(&mut self, iv: Interval<T>) {
+        if iv.is_empty() {
+            return;
+        }
+        let lo = self.intervals.partition_point(|x| x.end < iv.start);
+        let hi = self.intervals.partition_point(|x| x.start <= iv.end);
+        let merged = if lo < hi {
+            Interval::new(
+                iv.start.min(self.intervals[lo].start),
+                iv.end.max(self.intervals[hi - 1].end),
+            )
+        } else {
+            iv
+        };
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    /// Take out the numbers in `iv`
+    pub fn remove(&mut self, iv: Interval<T>) {
+        if iv.is_empty() {
+            return;
+        }
+        let lo = self.first_ending_after(iv.start);
+        let hi = self.intervals.partition_point(|x| x.start < iv.end);
+        if lo >= hi {
+            return;
+        }
+        let (before, _) = self.intervals[lo].difference(&iv);
+        let (_, after) = self.intervals[hi - 1].difference(&iv);
+        let kept = [before, after].into_iter().filter(|x| !x.is_empty());
+        self.intervals.splice(lo..hi, kept);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        other.iter().for_each(|iv| out.insert(iv));
+        out
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        let mut intervals = Vec::new();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let both = x.intersection(y);
+            if !both.is_empty() {
+                intervals.push(both);
+            }
+            // the one that ends first can't overlap anything else in the other set
+            if x.end <= y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        // the pieces are separated by the gaps of either set so they can't touch
+        Self { intervals }
+    }
+
+    /// The numbers in `self` but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        other.iter().for_each(|iv| out.remove(iv));
+        out
+    }
+
+    /// The numbers below `x` and the ones from `x` onwards
+    pub fn split_at(&self, x: T) -> (Self, Self) {
+        let i = self.first_ending_after(x);
+        let mut below = self.intervals[..i].to_vec();
+        let mut above = self.intervals[i..].to_vec();
+        if let Some(first) = above.first_mut().filter(|iv| iv.start < x) {
+            let (left, right) = first.split_at(x);
+            below.push(left);
+            *first = right;
+        }
+        (Self { intervals: below }, Self { intervals: above })
+    }
+}
+
+impl<T: Int> From<Interval<T>> for IntervalSet<T> {
+    fn from(iv: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(iv);
+        set
+    }
+}
+
+impl<T: Int> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|iv| set.insert(iv));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Everything is checked against bitmasks of the numbers 0..N
+    const N: u32 = 6;
+
+    fn mask_of(iv: Interval<u32>) -> u8 {
+        (iv.start..iv.end).fold(0, |m, i| m | 1 << i)
+    }
+
+    /// Every interval within 0..=N, including empty ones
+    fn all_intervals() -> impl Iterator<Item = Interval<u32>> {
+        (0..=N).flat_map(|start| (0..=N).map(move |end| Interval::new(start, end)))
+    }
+
+    /// Inserting the numbers one at a time, in a jumbled order
+    fn set_of(mask: u8) -> IntervalSet<u32> {
+        [3, 0, 5, 1, 4, 2]
+            .into_iter()
+            .filter(|i| mask & 1 << i != 0)
+            .map(|i| Interval::from_len(i, 1))
+            .collect()
+    }
+
+    /// Also checks that the set is normalized
+    fn set_mask(set: &IntervalSet<u32>) -> u8 {
+        for pair in set.intervals().windows(2) {
+            assert!(pair[0].end < pair[1].start, "{set:?} isn't normalized");
+        }
+        assert!(set.iter().all(|iv| !iv.is_empty()));
+        set.iter().map(mask_of).fold(0, |m, x| m | x)
+    }
+
+    #[test]
+    fn interval_ops() {
+        for a in all_intervals() {
+            let ma = mask_of(a);
+            assert_eq!(a.len(), ma.count_ones());
+            assert_eq!(a.is_empty(), ma == 0);
+            assert_eq!(a.last(), (ma != 0).then(|| 7 - ma.leading_zeros()));
+            for x in 0..=N {
+                assert_eq!(a.contains(x), ma & 1 << x != 0);
+                let (l, r) = a.split_at(x);
+                assert_eq!((mask_of(l), mask_of(r)), (ma & !(!0 << x), ma & !0 << x));
+            }
+            for b in all_intervals() {
+                let mb = mask_of(b);
+                assert_eq!(mask_of(a.intersection(&b)), ma & mb);
+                assert_eq!(a.intersects(&b), ma & mb != 0);
+                assert_eq!(a.contains_interval(&b), mb & !ma == 0);
+                let (before, after) = a.difference(&b);
+                assert_eq!(mask_of(before) | mask_of(after), ma & !mb);
+            }
+        }
+    }
+
+    #[test]
+    fn interval_constructors() {
+        assert_eq!(Interval::from_incl(2, 4), Interval::new(2, 5));
+        assert_eq!(Interval::from_len(2u64, 3), Interval::new(2, 5));
+        assert_eq!(Interval::new(5u64, 8).translate(4, 1), Interval::new(2, 5));
+        assert_eq!(Interval::new(5u64, 8).translate(1, 4), Interval::new(8, 11));
+    }
+
+    #[test]
+    fn set_ops() {
+        for ma in 0..1 << N {
+            let a = set_of(ma);
+            assert_eq!(set_mask(&a), ma);
+            assert_eq!(a.len(), ma.count_ones());
+            assert_eq!(a.is_empty(), ma == 0);
+            assert_eq!(a.min(), (ma != 0).then(|| ma.trailing_zeros()));
+            assert_eq!(a.max(), (ma != 0).then(|| 7 - ma.leading_zeros()));
+            for x in 0..=N {
+                assert_eq!(a.contains(x), ma & 1 << x != 0);
+                let (l, r) = a.split_at(x);
+                assert_eq!(
+                    (set_mask(&l), set_mask(&r)),
+                    (ma & !(!0 << x), ma & !0 << x)
+                );
+            }
+            for iv in all_intervals() {
+                let mi = mask_of(iv);
+                assert_eq!(a.contains_interval(&iv), mi & !ma == 0);
+                let mut b = a.clone();
+                b.insert(iv);
+                assert_eq!(set_mask(&b), ma | mi);
+                let mut b = a.clone();
+                b.remove(iv);
+                assert_eq!(set_mask(&b), ma & !mi);
+            }
+            for mb in 0..1 << N {
+                let b = set_of(mb);
+                assert_eq!(set_mask(&a.union(&b)), ma | mb);
+                assert_eq!(set_mask(&a.intersection(&b)), ma & mb);
+                assert_eq!(set_mask(&a.difference(&b)), ma & !mb);
+                assert_eq!(a == b, ma == mb);
+            }
+        }
+    }
+}
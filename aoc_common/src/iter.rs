@@ -0,0 +1,133 @@
+use std::iter;
+
+use num::{BigInt, BigUint};
+
+use crate::error::SolveError;
+
+/// Like [`Iterator::sum`] but the output type is the item type
+/// so it doesn't need to be annotated at every call site.
+pub trait SumT<T>: Iterator<Item = T> + Sized {
+    fn sumt(self) -> T;
+}
+impl<I: Iterator> SumT<I::Item> for I
+where
+    I::Item: iter::Sum,
+{
+    fn sumt(self) -> I::Item {
+        self.sum()
+    }
+}
+
+/// Integers that [`CheckedSum`] can add up
+pub trait CheckedAdd: Sized {
+    const ZERO: Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+macro_rules! impl_checked_add {
+    ($($t:ty),*) => {
+        $(impl CheckedAdd for $t {
+            const ZERO: Self = 0;
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+        })*
+    };
+}
+impl_checked_add!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Never overflows
+impl CheckedAdd for BigInt {
+    const ZERO: Self = BigInt::ZERO;
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+/// Never overflows
+impl CheckedAdd for BigUint {
+    const ZERO: Self = BigUint::ZERO;
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+/// Integers that [`CheckedProduct`] can multiply together
+pub trait CheckedMul: Sized {
+    const ONE: Self;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+macro_rules! impl_checked_mul {
+    ($($t:ty),*) => {
+        $(impl CheckedMul for $t {
+            const ONE: Self = 1;
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+        })*
+    };
+}
+impl_checked_mul!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Like [`SumT::sumt`] but an overflow is a [`SolveError`] instead of a panic
+/// (or a wrong answer in release builds).
+pub trait CheckedSum<T>: Iterator<Item = T> + Sized {
+    fn checked_sum(self) -> Result<T, SolveError>;
+}
+impl<I: Iterator> CheckedSum<I::Item> for I
+where
+    I::Item: CheckedAdd,
+{
+    fn checked_sum(mut self) -> Result<I::Item, SolveError> {
+        self.try_fold(I::Item::ZERO, CheckedAdd::checked_add)
+            .ok_or_else(SolveError::overflow)
+    }
+}
+
+/// Like [`Iterator::product`] but an overflow is a [`SolveError`], see [`CheckedSum`]
+pub trait CheckedProduct<T>: Iterator<Item = T> + Sized {
+    fn checked_product(self) -> Result<T, SolveError>;
+}
+impl<I: Iterator> CheckedProduct<I::Item> for I
+where
+    I::Item: CheckedMul,
+{
+    fn checked_product(mut self) -> Result<I::Item, SolveError> {
+        self.try_fold(I::Item::ONE, CheckedMul::checked_mul)
+            .ok_or_else(SolveError::overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sumt_sums_items() {
+        assert_eq!([1_u64, 2, 3].into_iter().sumt(), 6);
+        assert_eq!(Vec::<i32>::new().into_iter().sumt(), 0);
+    }
+
+    #[test]
+    fn checked_sum_reports_overflow() {
+        assert_eq!([1_u8, 2, 3].into_iter().checked_sum(), Ok(6));
+        assert_eq!([200_u8, 55].into_iter().checked_sum(), Ok(255));
+        assert_eq!(
+            [200_u8, 56].into_iter().checked_sum(),
+            Err(SolveError::overflow())
+        );
+        let big = [BigUint::from(u128::MAX), BigUint::from(1_u8)];
+        assert_eq!(
+            big.into_iter().checked_sum(),
+            Ok(BigUint::from(u128::MAX) + 1_u8)
+        );
+    }
+
+    #[test]
+    fn checked_product_reports_overflow() {
+        assert_eq!([3_u8, 5, 17].into_iter().checked_product(), Ok(255));
+        assert_eq!(Vec::<i32>::new().into_iter().checked_product(), Ok(1));
+        assert_eq!(
+            [16_u8, 16].into_iter().checked_product(),
+            Err(SolveError::overflow())
+        );
+    }
+}
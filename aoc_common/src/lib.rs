@@ -0,0 +1,30 @@
+//! Helpers shared between all the days (input parsing, iterator extensions, ...)
+pub mod answer;
+pub mod bitgrid;
+pub mod cycle;
+pub mod error;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod iter;
+pub mod lines;
+pub mod normalize;
+pub mod parse;
+pub mod rng;
+pub mod solution;
+pub mod trace;
+
+pub use answer::Answer;
+pub use bitgrid::BitGrid;
+pub use cycle::{find_cycle_brent, find_cycle_floyd, find_cycle_hashed, Cycle};
+pub use error::{ParseError, SolveError};
+pub use geom::{Dirn, IPos, Pos};
+pub use grid::{FromChar, Grid, ToChar};
+pub use interval::{Interval, IntervalSet};
+pub use iter::{CheckedProduct, CheckedSum, SumT};
+pub use lines::{blocks, non_empty_lines};
+pub use normalize::{normalize, InputFormat, Normalized};
+pub use parse::{parse_comma_list, parse_grid, parse_item, parse_sep_list, parse_ws_list};
+pub use rng::Rng;
+pub use solution::Solution;
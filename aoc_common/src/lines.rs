@@ -0,0 +1,46 @@
+/// All the lines in `contents` with surrounding whitespace trimmed
+/// and the empty ones removed
+pub fn non_empty_lines(contents: &str) -> impl Iterator<Item = &str> {
+    contents.lines().map(|x| x.trim()).filter(|x| !x.is_empty())
+}
+
+/// Split `contents` into blocks of (trimmed) lines separated by one or more blank lines.
+/// Never returns an empty block.
+pub fn blocks(contents: &str) -> Vec<Vec<&str>> {
+    let lines: Vec<_> = contents.lines().map(|x| x.trim()).collect();
+    lines
+        .split(|ln| ln.is_empty())
+        .filter(|block| !block.is_empty())
+        .map(|block| block.to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty_lines_trims_and_skips_blank() {
+        let v: Vec<_> = non_empty_lines("  ab \n\n\t\ncd\r\n  \nef").collect();
+        assert_eq!(v, ["ab", "cd", "ef"]);
+    }
+
+    #[test]
+    fn non_empty_lines_of_empty_input() {
+        assert_eq!(non_empty_lines("").count(), 0);
+        assert_eq!(non_empty_lines("\n \n").count(), 0);
+    }
+
+    #[test]
+    fn blocks_split_on_blank_lines() {
+        let b = blocks("#.\n.#\n\n##\n..\n");
+        assert_eq!(b, vec![vec!["#.", ".#"], vec!["##", ".."]]);
+    }
+
+    #[test]
+    fn blocks_ignore_repeated_and_surrounding_blank_lines() {
+        let b = blocks("\n\na\n\n\n \nb\nc\n\n");
+        assert_eq!(b, vec![vec!["a"], vec!["b", "c"]]);
+        assert!(blocks("").is_empty());
+    }
+}
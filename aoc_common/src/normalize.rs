@@ -0,0 +1,200 @@
+//! Tidying up the input text before a day parses it, so that the same input
+//! parses the same way whichever machine or editor it was saved with.
+use crate::error::ParseError;
+
+/// How a day's input is laid out, which decides what [`normalize`] can
+/// change without changing its meaning. Set with [`crate::Solution::INPUT_FORMAT`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputFormat {
+    /// Whether blank lines separate blocks of lines (otherwise they are dropped)
+    pub blocks: bool,
+    /// Whether whitespace at the start of a line is kept (it could be part of a grid)
+    pub keep_indent: bool,
+}
+
+impl InputFormat {
+    /// One item per line
+    pub const LINES: Self = Self {
+        blocks: false,
+        keep_indent: false,
+    };
+    /// The rows of a grid
+    pub const GRID: Self = Self {
+        blocks: false,
+        keep_indent: true,
+    };
+
+    /// The same lines, but in blocks separated by blank lines
+    pub const fn blocks(self) -> Self {
+        Self {
+            blocks: true,
+            ..self
+        }
+    }
+}
+
+impl Default for InputFormat {
+    fn default() -> Self {
+        Self::LINES
+    }
+}
+
+/// The normalized text of an input and where each of its lines came from,
+/// so that parse errors can point at the original
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized<'a> {
+    pub text: String,
+    /// The input without its byte order mark
+    original: &'a str,
+    /// For each line of `text`, the index of the line of `original` it came
+    /// from and how many chars were trimmed from its start
+    origins: Vec<(usize, usize)>,
+}
+
+impl Normalized<'_> {
+    /// Move an error located in `text` to the same place in the original input
+    pub fn locate(&self, mut e: ParseError) -> ParseError {
+        if e.line == 0 {
+            return e;
+        }
+        match self.origins.get(e.line - 1) {
+            Some(&(index, trimmed)) => {
+                e.line = index + 1;
+                e.column += trimmed;
+                e.line_text = self.original.lines().nth(index).unwrap_or("").to_string();
+            }
+            // past the last line, i.e. at the end of the input
+            None => {
+                let end = ParseError::at_end(self.original, "");
+                (e.line, e.column, e.line_text) = (end.line, end.column, end.line_text);
+            }
+        }
+        e
+    }
+}
+
+/// `input` with a UTF-8 byte order mark and `\r` line endings removed, and
+/// the whitespace at the end of each line (and at the start, unless
+/// `format.keep_indent`) trimmed. Blank lines are dropped, except that with
+/// `format.blocks` each run of them between two blocks becomes one empty line.
+/// Every line (including the last) ends with `\n`.
+pub fn normalize(input: &str, format: InputFormat) -> Normalized<'_> {
+    let original = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut text = String::with_capacity(original.len() + 1);
+    let mut origins = Vec::new();
+    let mut blank_since = None;
+    for (i, ln) in original.lines().enumerate() {
+        let trimmed = if format.keep_indent {
+            ln.trim_end()
+        } else {
+            ln.trim()
+        };
+        if trimmed.is_empty() {
+            if format.blocks && !text.is_empty() {
+                blank_since.get_or_insert(i);
+            }
+            continue;
+        }
+        if let Some(blank) = blank_since.take() {
+            text.push('\n');
+            origins.push((blank, 0));
+        }
+        text.push_str(trimmed);
+        text.push('\n');
+        let indent = ln.trim_end().len() - trimmed.len();
+        origins.push((i, ln[..indent].chars().count()));
+    }
+    Normalized {
+        text,
+        original,
+        origins,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLOCKS: InputFormat = InputFormat::LINES.blocks();
+
+    fn text(input: &str, format: InputFormat) -> String {
+        normalize(input, format).text
+    }
+
+    #[test]
+    fn line_endings_and_bom() {
+        let unix = "seeds: 1 2\nab\n";
+        for input in [
+            "\u{feff}seeds: 1 2\r\nab\r\n",
+            "seeds: 1 2\r\nab",
+            "seeds: 1 2\nab\n\n\n",
+        ] {
+            assert_eq!(text(input, InputFormat::LINES), unix);
+        }
+        assert_eq!(text("", InputFormat::LINES), "");
+        assert_eq!(text("\u{feff}\r\n \n", BLOCKS), "");
+    }
+
+    #[test]
+    fn whitespace_in_lines() {
+        let input = "\n  Time: 1  2 \t\n\n \nDistance: 3 4\n";
+        assert_eq!(
+            text(input, InputFormat::LINES),
+            "Time: 1  2\nDistance: 3 4\n"
+        );
+        assert_eq!(text(" .#\n#. \n", InputFormat::GRID), " .#\n#.\n");
+    }
+
+    #[test]
+    fn blank_lines_between_blocks() {
+        let input = "\n\n#.\n.#  \n\n \t\n\n##\r\n\r\n..\n\n";
+        assert_eq!(text(input, BLOCKS), "#.\n.#\n\n##\n\n..\n");
+        assert_eq!(
+            text(input, InputFormat::GRID.blocks()),
+            "#.\n.#\n\n##\n\n..\n"
+        );
+        assert_eq!(text(input, InputFormat::LINES), "#.\n.#\n##\n..\n");
+    }
+
+    #[test]
+    fn normalizing_twice_changes_nothing() {
+        let input = "\u{feff} a \r\n\r\n\r\n  b\r\n c  \n\n";
+        for format in [
+            InputFormat::LINES,
+            InputFormat::GRID,
+            BLOCKS,
+            InputFormat::GRID.blocks(),
+        ] {
+            let once = text(input, format);
+            assert_eq!(text(&once, format), once);
+        }
+    }
+
+    #[test]
+    fn errors_point_at_the_original_text() {
+        let input = "\u{feff}\r\n\r\nGame 1: 3 blue\r\n\r\n  Game 2: 4 purple\r\n";
+        let normalized = normalize(input, InputFormat::LINES);
+        let at = normalized.text.find("purple").unwrap();
+        let e = ParseError::new(&normalized.text[at..at + 6], "a color").locate(&normalized.text);
+        assert_eq!((e.line, e.column), (2, 11));
+        let e = normalized.locate(e);
+        assert_eq!((e.line, e.column), (5, 13));
+        assert_eq!(e.line_text, "  Game 2: 4 purple");
+
+        let e = normalized.locate(ParseError::at_end(&normalized.text, "a game"));
+        assert_eq!((e.line, e.column), (6, 1));
+        let unlocated = ParseError::new("purple", "a color");
+        assert_eq!(normalized.locate(unlocated.clone()), unlocated);
+    }
+
+    #[test]
+    fn block_separators_point_at_the_first_blank_line() {
+        let input = "a\n\n \nb\n";
+        let normalized = normalize(input, BLOCKS);
+        assert_eq!(normalized.text, "a\n\nb\n");
+        let e = ParseError::new(&normalized.text[2..2], "a block").locate(&normalized.text);
+        assert_eq!(normalized.locate(e).line, 2);
+        let e = ParseError::new(&normalized.text[3..4], "a block").locate(&normalized.text);
+        assert_eq!(normalized.locate(e).line, 4);
+    }
+}
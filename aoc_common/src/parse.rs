@@ -0,0 +1,185 @@
+use std::any;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// Parse a single item, e.g. a number. `s` should be a slice of the input so
+/// that the error can point at it.
+pub fn parse_item<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new(s, expected_name::<T>()))
+}
+
+/// Parse a whitespace-separated list, e.g. `"79 14  55 13"`
+pub fn parse_ws_list<'a, T: FromStr + 'a>(
+    s: &'a str,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    s.split_whitespace().map(parse_item)
+}
+
+/// Parse a comma-separated list, e.g. `"1,1,3"` (whitespace around items is ignored)
+pub fn parse_comma_list<'a, T: FromStr + 'a>(
+    s: &'a str,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    parse_sep_list(s, ',')
+}
+
+/// Parse a list of items separated by `sep` (whitespace around items is ignored)
+pub fn parse_sep_list<'a, T: FromStr + 'a>(
+    s: &'a str,
+    sep: char,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    s.trim().split(sep).map(|item| parse_item(item.trim()))
+}
+
+/// Strip `prefix` from `s`, e.g. `"Game "`
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("{prefix:?}")))
+}
+
+/// Split `s` at the first `sep`, e.g. `"AAA = (BBB, CCC)"` at `" = "`
+pub fn split_once<'a>(s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::new(s, format!("something containing {sep:?}")))
+}
+
+/// Parse `lines` as a rectangular grid with one tile per char.
+/// `tile` returns `None` for invalid chars, `expected` describes the valid ones.
+pub fn parse_grid<'a, T>(
+    lines: impl IntoIterator<Item = &'a str>,
+    tile: impl Fn(char) -> Option<T>,
+    expected: &str,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut grid: Vec<Vec<T>> = Vec::new();
+    for ln in lines {
+        let row = ln
+            .char_indices()
+            .map(|(i, c)| {
+                tile(c).ok_or_else(|| ParseError::new(&ln[i..i + c.len_utf8()], expected))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(first) = grid.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::new(
+                    ln,
+                    format!("a row of {} tiles", first.len()),
+                ));
+            }
+        }
+        grid.push(row);
+    }
+    Ok(grid)
+}
+
+fn expected_name<T>() -> String {
+    let name = any::type_name::<T>();
+    match name {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => "a non-negative integer".into(),
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => "an integer".into(),
+        _ => format!("a valid {name}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ws_list_any_whitespace() {
+        let v: Vec<u32> = parse_ws_list("  41 48 83  86\t17 ")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(v, [41, 48, 83, 86, 17]);
+    }
+
+    #[test]
+    fn ws_list_signed_and_wide() {
+        let v: Vec<i64> = parse_ws_list("10 -3 0 -12")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(v, [10, -3, 0, -12]);
+        let v: Vec<u64> = parse_ws_list("3917400079 1")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(v, [3917400079, 1]);
+    }
+
+    #[test]
+    fn ws_list_empty() {
+        assert_eq!(parse_ws_list::<u32>("   ").count(), 0);
+    }
+
+    #[test]
+    fn comma_list() {
+        let v: Vec<usize> = parse_comma_list("1,1,3").collect::<Result<_, _>>().unwrap();
+        assert_eq!(v, [1, 1, 3]);
+        let v: Vec<usize> = parse_comma_list(" 4, 5 ,6 ")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(v, [4, 5, 6]);
+    }
+
+    #[test]
+    fn sep_list() {
+        let v: Vec<u8> = parse_sep_list("1;2;3", ';')
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(v, [1, 2, 3]);
+    }
+
+    #[test]
+    fn bad_item_is_located() {
+        let input = "seeds: 1 2\n3 x 5\n";
+        let e = parse_ws_list::<u32>(&input[11..])
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.found, "x");
+        assert_eq!(e.expected, "a non-negative integer");
+    }
+
+    fn tile(c: char) -> Option<bool> {
+        match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn grid() {
+        let g = parse_grid(["#.", ".#"], tile, "'.' or '#'").unwrap();
+        assert_eq!(g, [[true, false], [false, true]]);
+    }
+
+    #[test]
+    fn grid_bad_tile_and_ragged_row() {
+        let input = "#..\n.x.\n#.\n";
+        let e = parse_grid(input.lines(), tile, "'.' or '#'")
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, "x"));
+        let input = "#..\n...\n#.\n";
+        let e = parse_grid(input.lines(), tile, "'.' or '#'")
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (3, 1, "a row of 3 tiles")
+        );
+    }
+
+    #[test]
+    fn missing_prefix_and_separator() {
+        let e = strip_prefix("Gam 1: 3 red", "Game ").unwrap_err();
+        assert_eq!(e.expected, "\"Game \"");
+        assert_eq!(
+            split_once("Card 1: 41 | 83", ": ").unwrap(),
+            ("Card 1", "41 | 83")
+        );
+        let e = split_once("Card 1 41 | 83", ": ").unwrap_err();
+        assert_eq!(e.expected, "something containing \": \"");
+    }
+}
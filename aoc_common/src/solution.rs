@@ -0,0 +1,62 @@
+use std::process;
+
+use crate::answer::Answer;
+use crate::error::{unwrap_or_exit, ParseError, SolveError};
+use crate::input::read_input_from_args;
+use crate::normalize::{normalize, InputFormat};
+use crate::rng::Rng;
+
+/// A day's solution: parses the input into a model and answers both parts from it
+pub trait Solution {
+    /// Day of the month (1-25)
+    const DAY: u32;
+    /// Directory of the day's crate, used to find its input and example files
+    const DIR: &'static str;
+    /// How many parts are solved (`part2` is never called if this is 1)
+    const PARTS: usize = 2;
+    /// How the input is laid out, see [`normalize`]
+    const INPUT_FORMAT: InputFormat = InputFormat::LINES;
+
+    type Model;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    /// Parse the input, which has already been through [`normalize`]
+    /// (so use [`parse_input`] to parse anything else)
+    fn parse(input: &str) -> Result<Self::Model, ParseError>;
+    fn part1(model: &Self::Model) -> Result<Self::Answer1, SolveError>;
+    fn part2(model: &Self::Model) -> Result<Self::Answer2, SolveError>;
+
+    /// A random valid input in the puzzle's format. `size` scales it
+    /// (what exactly it is, e.g. the number of lines, depends on the day).
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Normalize `input` for `S` (see [`Solution::INPUT_FORMAT`]) and parse it.
+/// Errors point at their line and column in `input`, not the normalized text.
+pub fn parse_input<S: Solution>(input: &str) -> Result<S::Model, ParseError> {
+    let normalized = normalize(input, S::INPUT_FORMAT);
+    S::parse(&normalized.text).map_err(|e| normalized.locate(e))
+}
+
+/// `main` of a day's own binary: reads the input selected by the command line
+/// and prints the answers
+pub fn run_main<S: Solution>() {
+    let input = read_input_from_args(S::DIR);
+    let model = unwrap_or_exit(parse_input::<S>(&input));
+    let mut failed = false;
+    let mut print = |part: usize, answer: Result<Answer, SolveError>| match answer {
+        Ok(answer) => println!("Part {part}: {answer}"),
+        Err(e) => {
+            eprintln!("error: part {part}: {e}");
+            failed = true;
+        }
+    };
+    print(1, S::part1(&model).map(Into::into));
+    if S::PARTS >= 2 {
+        print(2, S::part2(&model).map(Into::into));
+    }
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,74 @@
+//! Printing the intermediate state of a solution, off unless asked for with
+//! `-v` (for [`debug!`](crate::debug)) or `-vv` (also for [`trace!`](crate::trace)).
+//!
+//! The output goes to stderr so it doesn't get mixed up with the answers.
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Only what [`debug!`](crate::debug) prints, e.g. a summary of each line of the input
+pub const DEBUG: u8 = 1;
+/// Also what [`trace!`](crate::trace) prints, e.g. every step of a loop
+pub const TRACE: u8 = 2;
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub const USAGE: &str =
+    "  -v, -vv                  print what the solutions are doing (-vv for more detail)";
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn enabled(level: u8) -> bool {
+    verbosity() >= level
+}
+
+/// How much more verbose `arg` asks for (`None` if it isn't `-v`, `-vv`, ...)
+pub fn parse_verbosity_arg(arg: &str) -> Option<u8> {
+    match arg {
+        "--verbose" => Some(1),
+        _ => {
+            let vs = arg.strip_prefix('-')?;
+            let n = vs.len();
+            (n > 0 && vs.bytes().all(|b| b == b'v')).then(|| u8::try_from(n).unwrap_or(u8::MAX))
+        }
+    }
+}
+
+/// Print to stderr if running with `-v` or more
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::DEBUG) {
+            eprintln!("[{}] {}", module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+/// Print to stderr if running with `-vv` or more
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::TRACE) {
+            eprintln!("[{}] {}", module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbosity_args() {
+        assert_eq!(parse_verbosity_arg("-v"), Some(1));
+        assert_eq!(parse_verbosity_arg("-vv"), Some(2));
+        assert_eq!(parse_verbosity_arg("--verbose"), Some(1));
+        assert_eq!(parse_verbosity_arg("-"), None);
+        assert_eq!(parse_verbosity_arg("-vx"), None);
+        assert_eq!(parse_verbosity_arg("v"), None);
+    }
+}
@@ -0,0 +1,14 @@
+//! Shared code of the fuzz targets (one per day, in `fuzz_targets/`)
+
+/// Run every part of `day` on `data`. Bad input should only ever give an
+/// error, so any panic (which libFuzzer reports as a crash) is a bug.
+pub fn run_day(day: u32, data: &[u8]) {
+    // the runner rejects input that isn't UTF-8 before it gets to the days
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let day = aoc::days::get_day(day).expect("every fuzz target is for a solved day");
+    for part in day.parts {
+        let _ = part(input);
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::Rng;
+
+const WORDS: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of letters, digits and spelled-out digits (with at least 1 real digit each)
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.range(1..8) {
+            match rng.below(3) {
+                0 => line.push((b'1' + rng.below(9) as u8) as char),
+                1 => line.push_str(rng.choose::<&str>(WORDS)),
+                _ => line.push((b'a' + rng.below(26) as u8) as char),
+            }
+        }
+        let digit = (b'1' + rng.below(9) as u8) as char;
+        line.insert(rng.index(line.len() + 1), digit);
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
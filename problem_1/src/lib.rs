@@ -0,0 +1,100 @@
+use aoc_common::{CheckedSum, ParseError, Rng, Solution, SolveError};
+use std::iter;
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Every line is valid here (a line without a digit is only a problem when solving)
+pub fn parse(contents: &str) -> Result<Vec<String>, ParseError> {
+    Ok(contents.lines().map(String::from).collect())
+}
+
+fn no_digits(ln: &str) -> SolveError {
+    SolveError::new(format!("line {ln:?} has no digits"))
+}
+
+pub fn part1(lines: &[String]) -> Result<u64, SolveError> {
+    let lines = lines.iter();
+    let nums_as_str = lines.map(|ln| {
+        let nums: Vec<_> = ln.chars().filter(|c| c.is_ascii_digit()).collect();
+        Ok(format!(
+            "{}{}",
+            nums.first().ok_or_else(|| no_digits(ln))?,
+            nums.last().ok_or_else(|| no_digits(ln))?
+        ))
+    });
+    let nums = nums_as_str.map(|s| s.map(|s| s.parse::<u64>().unwrap()));
+    nums.collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .checked_sum()
+}
+
+const NUM_WORDS: &[&str] = &[
+    "", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn parsed_tup(tup: &(usize, char)) -> (usize, u64) {
+    (tup.0, tup.1.to_string().parse().unwrap())
+}
+fn to_signed_tup(tup: &(usize, u64)) -> (isize, u64) {
+    (tup.0 as isize, tup.1)
+}
+
+pub fn part2(lines: &[String]) -> Result<u64, SolveError> {
+    let lines = lines.iter();
+    let nums = lines.map(|ln| {
+        let nums: Vec<_> = ln
+            .chars()
+            .enumerate()
+            .filter(|(_i, c)| c.is_ascii_digit())
+            .collect();
+        let first_digit = nums.first().map(parsed_tup);
+        let last_digit = nums.last().map(|tup| to_signed_tup(&parsed_tup(tup)));
+        let first = (1..=9)
+            .map(|num| ln.find(NUM_WORDS[num]).map(|idx| (idx, num as u64)))
+            .chain(iter::once(first_digit))
+            .flatten()
+            .min_by_key(|(i, _value)| i.to_owned())
+            .ok_or_else(|| no_digits(ln))?
+            .1;
+        let last = (1..=9)
+            .map(|num| {
+                ln.rfind(NUM_WORDS[num])
+                    .map(|i| ((i + NUM_WORDS[num].len() - 1) as isize, num as u64))
+            })
+            .chain(iter::once(last_digit))
+            .flatten()
+            .max_by_key(|(i, _value)| i.to_owned())
+            .ok_or_else(|| no_digits(ln))?
+            .1;
+        Ok(10 * first + last)
+    });
+    nums.collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .checked_sum()
+}
+
+pub struct Day1;
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const DIR: &'static str = CRATE_DIR;
+
+    type Model = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(lines: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(lines)
+    }
+    fn part2(lines: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(lines)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
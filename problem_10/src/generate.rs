@@ -0,0 +1,136 @@
+use aoc_common::Rng;
+
+/// (dx, dy) for N, E, S, W
+const DIRNS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const N: usize = 0;
+const E: usize = 1;
+const S: usize = 2;
+const W: usize = 3;
+
+fn pipe_char(mut dirns: [usize; 2]) -> u8 {
+    dirns.sort_unstable();
+    match dirns {
+        [N, S] => b'|',
+        [E, W] => b'-',
+        [N, E] => b'L',
+        [N, W] => b'J',
+        [S, W] => b'7',
+        [E, S] => b'F',
+        _ => unreachable!("a pipe connects 2 different directions"),
+    }
+}
+
+/// Random tree of `n_nodes` nodes (including (0, 0)) in an `n` x `n` grid:
+/// `tree[y][x][dirn]` is whether (x, y) is connected to its neighbour in that direction
+fn random_tree(rng: &mut Rng, n: usize, n_nodes: usize) -> Vec<Vec<[bool; 4]>> {
+    let mut tree = vec![vec![[false; 4]; n]; n];
+    let mut visited = vec![vec![false; n]; n];
+    visited[0][0] = true;
+    let mut n_visited = 1;
+    let mut stack = vec![(0, 0)];
+    while let Some(&(x, y)) = stack.last() {
+        if n_visited >= n_nodes {
+            break;
+        }
+        let options: Vec<_> = (0..4)
+            .filter_map(|d| {
+                let (nx, ny) = (x as isize + DIRNS[d].0, y as isize + DIRNS[d].1);
+                let (nx, ny) = (usize::try_from(nx).ok()?, usize::try_from(ny).ok()?);
+                (nx < n && ny < n && !visited[ny][nx]).then_some((d, nx, ny))
+            })
+            .collect();
+        if options.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let &(d, nx, ny) = rng.choose(&options);
+        tree[y][x][d] = true;
+        tree[ny][nx][(d + 2) % 4] = true;
+        visited[ny][nx] = true;
+        n_visited += 1;
+        stack.push((nx, ny));
+    }
+    tree
+}
+
+/// A maze of about `size` x `size` tiles. The loop goes around a random tree
+/// (so it twists everywhere, with tiles inside it between its sides) and every other tile is random junk.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = (size.saturating_sub(1) / 4).max(1);
+    let n_nodes = rng.range((n * n / 2) as u64..(n * n) as u64 + 1) as usize;
+    let tree = random_tree(rng, n, n_nodes);
+    // Walk around the tree on a grid with 2x2 cells per node, which visits every cell of its nodes once.
+    // Each cell is connected to its neighbours in the node except where that side of the
+    // node has a tree edge, where it goes into the next node instead.
+    let next_dirns = |(x, y): (usize, usize)| -> [usize; 2] {
+        let node = tree[y / 2][x / 2];
+        let (left, top) = (x % 2 == 0, y % 2 == 0);
+        let (vert_out, horiz_out) = (if top { N } else { S }, if left { W } else { E });
+        [
+            if node[vert_out] {
+                vert_out
+            } else {
+                (horiz_out + 2) % 4
+            },
+            if node[horiz_out] {
+                horiz_out
+            } else {
+                (vert_out + 2) % 4
+            },
+        ]
+    };
+    let mut cells = vec![(0, 0)];
+    let mut prev_dirn = None;
+    loop {
+        let &(x, y) = cells.last().unwrap();
+        let d = next_dirns((x, y))
+            .into_iter()
+            .find(|d| Some((d + 2) % 4) != prev_dirn)
+            .unwrap();
+        prev_dirn = Some(d);
+        let next = (
+            (x as isize + DIRNS[d].0) as usize,
+            (y as isize + DIRNS[d].1) as usize,
+        );
+        if next == cells[0] {
+            break;
+        }
+        cells.push(next);
+    }
+    // Scale the loop up by 2 so that there are tiles between its sides (some inside, some
+    // outside) and leave a border of 1 tile around it.
+    let side = 4 * n + 1;
+    let mut grid = vec![vec![b'.'; side]; side];
+    let mut on_loop = vec![vec![false; side]; side];
+    let scaled: Vec<_> = (0..cells.len())
+        .flat_map(|i| {
+            let ((x0, y0), (x1, y1)) = (cells[i], cells[(i + 1) % cells.len()]);
+            [(2 * x0 + 1, 2 * y0 + 1), (x0 + x1 + 1, y0 + y1 + 1)]
+        })
+        .collect();
+    let dirn_between = |(x0, y0): (usize, usize), (x1, y1): (usize, usize)| {
+        let d = (x1 as isize - x0 as isize, y1 as isize - y0 as isize);
+        DIRNS.iter().position(|dirn| *dirn == d).unwrap()
+    };
+    for (i, &(x, y)) in scaled.iter().enumerate() {
+        let prev = scaled[(i + scaled.len() - 1) % scaled.len()];
+        let next = scaled[(i + 1) % scaled.len()];
+        grid[y][x] = pipe_char([dirn_between((x, y), prev), dirn_between((x, y), next)]);
+        on_loop[y][x] = true;
+    }
+    let (sx, sy) = *rng.choose(&scaled);
+    grid[sy][sx] = b'S';
+    for y in 0..side {
+        for x in 0..side {
+            let next_to_start = x.abs_diff(sx) + y.abs_diff(sy) == 1;
+            if !on_loop[y][x] && !next_to_start && rng.chance(0.6) {
+                grid[y][x] = *rng.choose(b"|-LJ7F");
+            }
+        }
+    }
+    let lines: Vec<_> = grid
+        .into_iter()
+        .map(|ln| String::from_utf8(ln).unwrap())
+        .collect();
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,304 @@
+use aoc_common::{
+    Dirn, FromChar, Grid, InputFormat, ParseError, Pos, Rng, Solution, SolveError, ToChar,
+};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::mem;
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TileType {
+    PipeVert,
+    PipeHoriz,
+    PipeNE,
+    PipeNW,
+    PipeSW,
+    PipeSE,
+    #[default]
+    Nothing,
+    Start,
+}
+impl FromChar for TileType {
+    const EXPECTED: &'static str = "a tile (one of |-LJ7F.S)";
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            '|' => Self::PipeVert,
+            '-' => Self::PipeHoriz,
+            'L' => Self::PipeNE,
+            'J' => Self::PipeNW,
+            '7' => Self::PipeSW,
+            'F' => Self::PipeSE,
+            '.' => Self::Nothing,
+            'S' => Self::Start,
+            _ => return None,
+        })
+    }
+}
+impl ToChar for TileType {
+    fn to_char(&self) -> char {
+        match self {
+            Self::PipeVert => '|',
+            Self::PipeHoriz => '-',
+            Self::PipeNE => 'L',
+            Self::PipeNW => 'J',
+            Self::PipeSW => '7',
+            Self::PipeSE => 'F',
+            Self::Nothing => '.',
+            Self::Start => 'S',
+        }
+    }
+}
+impl TileType {
+    #[inline]
+    fn get_connector_pair(self) -> Option<[Dirn; 2]> {
+        use Dirn::*;
+        match self {
+            Self::PipeVert => Some([N, S]),
+            Self::PipeHoriz => Some([E, W]),
+            Self::PipeNW => Some([N, W]),
+            Self::PipeNE => Some([N, E]),
+            Self::PipeSW => Some([S, W]),
+            Self::PipeSE => Some([S, E]),
+            Self::Nothing | Self::Start => None,
+        }
+    }
+
+    fn from_connector_pair(mut pair: [Dirn; 2]) -> Self {
+        pair.sort_unstable();
+        use Dirn::*;
+        match pair {
+            [N, S] => Self::PipeVert,
+            [N, E] => Self::PipeNE,
+            [N, W] => Self::PipeNW,
+            [E, S] => Self::PipeSE,
+            [E, W] => Self::PipeHoriz,
+            [S, W] => Self::PipeSW,
+            _ => panic!("Unknown connector pair"),
+        }
+    }
+
+    fn has_connector(self, dirn: Dirn) -> bool {
+        match self {
+            Self::Nothing => false,
+            Self::Start => true,
+            _ => self.get_connector_pair().unwrap().contains(&dirn),
+        }
+    }
+}
+
+pub fn parse(contents: &str) -> Result<Grid<TileType>, ParseError> {
+    let grid = Grid::parse(contents.lines()).map_err(|e| e.locate(contents))?;
+    if grid.is_empty() {
+        return Err(ParseError::at_end(contents, "a grid of tiles"));
+    }
+    let mut starts = contents
+        .match_indices('S')
+        .map(|(i, s)| &contents[i..i + s.len()]);
+    if starts.next().is_none() {
+        return Err(ParseError::at_end(contents, "a start tile 'S'"));
+    }
+    if let Some(second) = starts.next() {
+        return Err(ParseError::new(second, "only one start tile").locate(contents));
+    }
+    Ok(grid)
+}
+
+fn find_start(grid: &Grid<TileType>) -> Pos {
+    grid.indexed_iter()
+        .find_map(|(pos, t)| (*t == TileType::Start).then_some(pos))
+        .expect("parse checks that there is a start")
+}
+
+// "always two [connecting tiles] there are, no more, no less"
+fn find_connecting_to_start(
+    grid: &Grid<TileType>,
+    start_pos: Pos,
+    gsize: Pos,
+) -> Result<[(Dirn, Pos); 2], SolveError> {
+    start_pos
+        .neighbours4(gsize)
+        .filter_map(|(dirn_from_start, pos)| {
+            grid[pos]
+                .has_connector(dirn_from_start.opp())
+                .then_some((dirn_from_start, pos))
+        })
+        .collect_vec()
+        .try_into()
+        .map_err(|adj: Vec<_>| {
+            SolveError::new(format!(
+                "the start should connect to 2 pipes, not {}",
+                adj.len()
+            ))
+        })
+}
+
+fn find_next(
+    grid: &Grid<TileType>,
+    (dirn_from_prev, pos): (Dirn, Pos),
+    gsize: Pos,
+) -> Result<(Dirn, Pos), SolveError> {
+    let dirn_to_prev = dirn_from_prev.opp();
+    let curr_tile = grid[pos];
+    let curr_connectors = curr_tile
+        .get_connector_pair()
+        .expect("find_next should only be called on a normal tile");
+    let dirn_to_next = curr_connectors
+        .iter()
+        .copied()
+        .filter(|c| *c != dirn_to_prev)
+        .exactly_one()
+        .expect("Each connector should have 2 connections: 1 to the prev, 1 to next");
+    aoc_common::trace!("find_next: {:?};   {:?}", dirn_to_next, pos);
+    let broken = || SolveError::new(format!("the loop is broken after {}", pos.describe()));
+    let next_pos = pos.step(dirn_to_next, gsize).ok_or_else(broken)?;
+    if !grid[next_pos].has_connector(dirn_to_next.opp()) {
+        return Err(broken());
+    }
+    Ok((dirn_to_next, next_pos))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum LoopControl {
+    Continue,
+    Break,
+}
+
+#[must_use = "Should check to end loop or not"]
+fn handle_next_node(
+    grid: &Grid<TileType>,
+    curr_info: &mut ((Dirn, Pos), usize),
+    dist_map: &mut HashMap<Pos, usize>,
+    gsize: Pos,
+) -> Result<LoopControl, SolveError> {
+    aoc_common::trace!("{:?};   {} found;   {:?}", curr_info, dist_map.len(), gsize);
+    // this mem::replace is sorta hacky but whatev
+    let _ = mem::replace(
+        curr_info,
+        (find_next(grid, curr_info.0, gsize)?, curr_info.1 + 1),
+    );
+    // if it is in, already found by traversing other so finished, ...
+    if dist_map.contains_key(&curr_info.0 .1) {
+        return Ok(LoopControl::Break);
+    }
+    // ... else, add it and continue
+    dist_map.insert(curr_info.0 .1, curr_info.1);
+    Ok(LoopControl::Continue)
+}
+
+// -> (grid, dist_map, start_tile)
+fn get_loop_dists(grid: &Grid<TileType>) -> Result<(HashMap<Pos, usize>, TileType), SolveError> {
+    aoc_common::trace!("{grid}");
+    let gsize = grid.size();
+    let start_pos = find_start(grid);
+    let start_adj = find_connecting_to_start(grid, start_pos, gsize)?;
+    // rather unidiomatic rust but whatever... (should've / could've used fold_while)
+    let mut dist_map: HashMap<Pos, usize> =
+        HashMap::from([(start_pos, 0), (start_adj[0].1, 1), (start_adj[1].1, 1)]);
+    let mut curr = start_adj.map(|v| (v, 1));
+    loop {
+        if handle_next_node(grid, &mut curr[0], &mut dist_map, gsize)? == LoopControl::Break {
+            break;
+        }
+        if handle_next_node(grid, &mut curr[1], &mut dist_map, gsize)? == LoopControl::Break {
+            break;
+        }
+    }
+    let start_connection_dirns = start_adj.map(|(dirn_from_start, _pos)| dirn_from_start);
+    let start_tile = TileType::from_connector_pair(start_connection_dirns);
+    aoc_common::debug!(
+        "the loop is {} tiles long, S is {start_tile:?}",
+        dist_map.len()
+    );
+    Ok((dist_map, start_tile))
+}
+
+pub fn part1(grid: &Grid<TileType>) -> Result<usize, SolveError> {
+    let (dist_map, _) = get_loop_dists(grid)?;
+    aoc_common::trace!("{:?}", dist_map);
+    let m = dist_map
+        .into_iter()
+        .max_by_key(|(_pos, dist)| *dist)
+        .unwrap();
+    Ok(m.1)
+}
+
+pub fn part2(grid: &Grid<TileType>) -> Result<i64, SolveError> {
+    let (dist_map, start_tile_is) = get_loop_dists(grid)?;
+    // 1. Remove non-loop 'junk' tiles and replace start with appropriate normal tile
+    let new_grid = Grid::from_fn(grid.width(), grid.height(), |lni, xi| {
+        let t = grid[(lni, xi)];
+        if !dist_map.contains_key(&Pos::new(lni, xi)) {
+            TileType::default()
+        } else if t == TileType::Start {
+            // replace start with appropriate tile
+            start_tile_is
+        } else {
+            t
+        }
+    });
+    aoc_common::trace!("just the loop:\n{new_grid}");
+    // So here '.' means not part of the loop
+
+    // NOTE: A line will always be on a boundary
+    // 2. For each space, check how many vertical boundary tiles are to its left (only count one that spearate the top half of the tile)
+    //      if num changes is even, outside. Else, inside. Because each boundary change MUST mean a change in in/out-ness
+    //      so even (0, 2, etc.) means out as 0 is out (and odd means in as 1 is in)
+    //    This is like casting a ray left from a point in the top half of the tile,
+    //      and counting the intersections to determine if a point is inside any polygon.
+    let sum_inside = new_grid
+        .rows()
+        .map(|ln| {
+            let (_, sum) = ln.iter().fold(
+                (/*is_inside*/ false, /*sum so far=0*/ 0),
+                |(prev_is_inside, prev_sum), curr_tile| {
+                    use TileType::*;
+                    match curr_tile {
+                        Nothing => (
+                            prev_is_inside,
+                            prev_sum + if prev_is_inside { 1 } else { 0 },
+                        ),
+                        Start => panic!("Start tile should've been filtered out"),
+                        // tile with a boundary at the top
+                        PipeVert | PipeNE | PipeNW => (
+                            /*crossed a boundary so invert inside-ness*/ !prev_is_inside,
+                            prev_sum,
+                        ),
+                        // explicitly show pipe without top boundary to check none missing
+                        PipeHoriz | PipeSE | PipeSW => (prev_is_inside, prev_sum),
+                    }
+                },
+            );
+            sum
+        })
+        .sum::<i64>();
+    Ok(sum_inside)
+}
+
+pub struct Day10;
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const DIR: &'static str = CRATE_DIR;
+    const INPUT_FORMAT: InputFormat = InputFormat::GRID;
+
+    type Model = Grid<TileType>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(grid: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(grid)
+    }
+    fn part2(grid: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(grid)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::Rng;
+
+/// About `size` galaxies in a square image where 1 in 10 of the rows and columns are empty
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // about 1 in 20 tiles has a galaxy
+    let side = ((size * 20) as f64).sqrt().ceil().max(1.0) as usize;
+    let empty_cols: Vec<_> = (0..side).map(|_| rng.chance(0.1)).collect();
+    let p_galaxy = size as f64 / (side * side) as f64 / 0.81;
+    let mut out = String::with_capacity((side + 1) * side);
+    for _ in 0..side {
+        let empty_row = rng.chance(0.1);
+        for empty_col in &empty_cols {
+            let galaxy = !empty_row && !empty_col && rng.chance(p_galaxy);
+            out.push(if galaxy { '#' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,186 @@
+use aoc_common::{
+    BitGrid, CheckedSum, FromChar, Grid, InputFormat, ParseError, Pos, Rng, Solution, SolveError,
+    ToChar,
+};
+use itertools::Itertools;
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Tile {
+    #[default]
+    Empty,
+    Galaxy,
+}
+impl FromChar for Tile {
+    const EXPECTED: &'static str = "'.' or '#'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '#' => Some(Self::Galaxy),
+            _ => None,
+        }
+    }
+}
+impl ToChar for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Galaxy => '#',
+        }
+    }
+}
+
+fn duplicate_empty_rows(grid: &BitGrid) -> BitGrid {
+    let old_rows = (0..grid.height())
+        .flat_map(|lni| {
+            if grid.row_count_ones(lni) == 0 {
+                vec![lni, lni]
+            } else {
+                vec![lni]
+            }
+        })
+        .collect_vec();
+    let mut out = BitGrid::new(grid.width(), old_rows.len());
+    for (new_lni, old_lni) in old_rows.into_iter().enumerate() {
+        out.row_mut(new_lni).copy_from_slice(grid.row(old_lni));
+    }
+    out
+}
+fn duplicate_empty_cols(grid: &BitGrid) -> BitGrid {
+    // the columns are the rows of the transposed grid
+    duplicate_empty_rows(&grid.transpose()).transpose()
+}
+
+/// The positions of the galaxies
+pub fn parse(contents: &str) -> Result<BitGrid, ParseError> {
+    let grid: Grid<Tile> = Grid::parse(contents.lines()).map_err(|e| e.locate(contents))?;
+    if grid.is_empty() {
+        return Err(ParseError::at_end(contents, "an image of the universe"));
+    }
+    Ok(BitGrid::from_grid(&grid, |t| *t == Tile::Galaxy))
+}
+
+fn find_galaxies(grid: &BitGrid) -> Vec<Pos> {
+    grid.iter_ones().collect_vec()
+}
+
+pub fn part1(grid: &BitGrid) -> Result<u64, SolveError> {
+    let grid = duplicate_empty_rows(grid);
+    let grid = duplicate_empty_cols(&grid);
+    aoc_common::trace!("expanded:\n{grid}");
+
+    let posn_list = find_galaxies(&grid);
+    let posn_pairs = posn_list.iter().tuple_combinations();
+    posn_pairs
+        .map(|(apos, bpos)| apos.manhattan_dist(*bpos) as u64)
+        .checked_sum()
+}
+
+fn find_empty_rows(grid: &BitGrid) -> Vec<usize> {
+    (0..grid.height())
+        .filter(|lni| grid.row_count_ones(*lni) == 0)
+        .collect()
+}
+fn find_empty_cols(grid: &BitGrid) -> Vec<usize> {
+    find_empty_rows(&grid.transpose())
+}
+
+fn manhattan_dist_expanded(
+    a: Pos,
+    b: Pos,
+    empty_rows: &[usize],
+    empty_cols: &[usize],
+    expansion_size: u64,
+) -> Option<u64> {
+    let lo = Pos::new(a.row.min(b.row), a.col.min(b.col));
+    let hi = Pos::new(a.row.max(b.row), a.col.max(b.col));
+    let lni_range = lo.row..=hi.row;
+    let xi_range = lo.col..=hi.col;
+    let n_expanded_rows = empty_rows
+        .iter()
+        .filter(|lni| lni_range.contains(lni))
+        .count();
+    let n_expanded_cols = empty_cols.iter().filter(|xi| xi_range.contains(xi)).count();
+    // each expanded row/col adds extra (expansion_size - 1) onto the manhattan distance
+    // so output = non_expanded + expanded * expansion_size
+    //           = non_expanded + expanded + expanded * (expansion_size - 1)
+    //           =    manhattan_dist       + expanded * (expansion_size - 1)
+    let n_expanded = (n_expanded_cols + n_expanded_rows) as u64;
+    n_expanded
+        .checked_mul(expansion_size - 1)?
+        .checked_add(a.manhattan_dist(b) as u64)
+}
+
+const EXPANSION_SIZE: u64 = 1_000_000;
+pub fn part2(grid: &BitGrid) -> Result<u64, SolveError> {
+    sum_dists_expanded(grid, EXPANSION_SIZE)
+}
+
+/// Sum of the distances between every pair of galaxies when each empty row
+/// and column is replaced by `expansion_size` of them
+fn sum_dists_expanded(grid: &BitGrid, expansion_size: u64) -> Result<u64, SolveError> {
+    let empty_rows = find_empty_rows(grid);
+    let empty_cols = find_empty_cols(grid);
+    aoc_common::debug!("empty rows: {empty_rows:?}, empty columns: {empty_cols:?}");
+
+    let posn_list = find_galaxies(grid);
+    let posn_pairs = posn_list.iter().tuple_combinations();
+
+    let dists = posn_pairs
+        .map(|(apos, bpos)| {
+            manhattan_dist_expanded(*apos, *bpos, &empty_rows, &empty_cols, expansion_size)
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(SolveError::overflow)?;
+    dists.into_iter().checked_sum()
+}
+
+pub struct Day11;
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const DIR: &'static str = CRATE_DIR;
+    const INPUT_FORMAT: InputFormat = InputFormat::GRID;
+
+    type Model = BitGrid;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(grid: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(grid)
+    }
+    fn part2(grid: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(grid)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::{quickcheck, TestResult};
+
+    quickcheck! {
+        // actually duplicating the empty rows and columns (part 1) should be
+        // the same as counting them twice
+        fn duplicating_is_expanding_by_2(rows: Vec<Vec<bool>>) -> TestResult {
+            // pad the rows to the same length (the image has to be a rectangle)
+            let width = rows.iter().map(Vec::len).max().unwrap_or(0).min(20);
+            if width == 0 {
+                return TestResult::discard();
+            }
+            let height = rows.len().min(20);
+            let grid = BitGrid::from_fn(width, height, |lni, xi| rows[lni].get(xi) == Some(&true));
+            TestResult::from_bool(part1(&grid) == sum_dists_expanded(&grid, 2))
+        }
+    }
+}
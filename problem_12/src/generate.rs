@@ -0,0 +1,34 @@
+use aoc_common::Rng;
+
+/// `size` rows of 1-16 springs (more could overflow part 2). Each row is made
+/// from a random arrangement (so it has at least 1 solution) with some springs, and sometimes long
+/// runs of them, replaced by '?'.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let len = rng.range(1..17) as usize;
+        let mut springs: Vec<u8> = (0..len)
+            .map(|_| if rng.chance(0.5) { b'#' } else { b'.' })
+            .collect();
+        springs[rng.index(len)] = b'#';
+        let groups: Vec<_> = springs
+            .split(|s| *s == b'.')
+            .filter(|g| !g.is_empty())
+            .map(|g| g.len().to_string())
+            .collect();
+        let p_unknown = rng.range(2..8) as f64 / 10.0;
+        for s in &mut springs {
+            if rng.chance(p_unknown) {
+                *s = b'?';
+            }
+        }
+        if rng.chance(0.2) {
+            let start = rng.index(len);
+            let end = rng.range(start as u64..len as u64 + 1) as usize;
+            springs[start..end].fill(b'?');
+        }
+        out.push_str(std::str::from_utf8(&springs).unwrap());
+        out.push_str(&format!(" {}\n", groups.join(",")));
+    }
+    out
+}
@@ -0,0 +1,216 @@
+use aoc_common::{parse_comma_list, CheckedSum, ParseError, Rng, Solution, SolveError};
+use itertools::Itertools;
+use std::mem;
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum State {
+    Normal,
+    Broken,
+    Unknown,
+}
+impl State {
+    fn from_char(c: char) -> Option<Self> {
+        use State::*;
+        match c {
+            '.' => Some(Normal),
+            '#' => Some(Broken),
+            '?' => Some(Unknown),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    states: Vec<State>,
+    nums: Vec<usize>,
+}
+
+fn parse_states(state_s: &str) -> Result<Vec<State>, ParseError> {
+    state_s
+        .char_indices()
+        .map(|(i, c)| {
+            State::from_char(c).ok_or_else(|| {
+                ParseError::new(&state_s[i..i + c.len_utf8()], "a spring ('.', '#' or '?')")
+            })
+        })
+        .collect()
+}
+fn parse_line(line: &str) -> Result<Line, ParseError> {
+    let (state_s, nums_s) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(line, "'<springs> <nums>'"))?;
+    let nums: Vec<usize> = parse_comma_list(nums_s).try_collect()?;
+    if nums.contains(&0) {
+        return Err(ParseError::new(nums_s, "group sizes of at least 1"));
+    }
+    Ok(Line {
+        states: parse_states(state_s)?,
+        nums,
+    })
+}
+
+pub fn parse(contents: &str) -> Result<Vec<Line>, ParseError> {
+    contents
+        .lines()
+        .map(parse_line)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(contents))
+}
+
+/// Number of ways of placing groups of broken springs of `lengths` in `states`,
+/// `None` if there are too many to fit in a usize.
+///
+/// Every sub-problem is a suffix of the states and a suffix of the lengths so
+/// this fills in a table of them from the end: `combs[i]` is the number of ways
+/// of placing the remaining groups in `states[i..]`. Only the row for the
+/// previous group is needed at a time so it's O(states * lengths) time and
+/// O(states) memory, without recursing (long rows would overflow the stack).
+fn count_combs(states: &[State], lengths: &[usize]) -> Option<usize> {
+    let n = states.len();
+    // index of the first Normal at or after each index (n if there isn't one)
+    let mut next_normal = vec![n; n + 1];
+    for i in (0..n).rev() {
+        next_normal[i] = if states[i] == State::Normal {
+            i
+        } else {
+            next_normal[i + 1]
+        };
+    }
+    // no lengths left so the rest must be normal or unknown (i.e. not broken) = 1
+    // or if doesn't match, 0
+    let mut combs = vec![Some(1_usize); n + 1];
+    for i in (0..n).rev() {
+        combs[i] = if states[i] == State::Broken {
+            Some(0)
+        } else {
+            combs[i + 1]
+        };
+    }
+    let mut next_combs = vec![Some(0_usize); n + 1];
+    for &len_curr in lengths.iter().rev() {
+        // no states left to put this group in
+        next_combs[n] = Some(0);
+        for i in (0..n).rev() {
+            // either this state is normal...
+            let skip = if states[i] == State::Broken {
+                Some(0)
+            } else {
+                next_combs[i + 1]
+            };
+            // ... or the group starts here (so can't have any normal states in it)
+            // and is followed by a normal state or the end
+            let place = match i.checked_add(len_curr) {
+                Some(end_excl) if end_excl <= n && next_normal[i] >= end_excl => {
+                    match states.get(end_excl) {
+                        None => combs[n],
+                        Some(State::Broken) => Some(0),
+                        Some(_) => combs[end_excl + 1],
+                    }
+                }
+                _ => Some(0),
+            };
+            next_combs[i] = skip.zip(place).and_then(|(a, b)| a.checked_add(b));
+        }
+        mem::swap(&mut combs, &mut next_combs);
+    }
+    combs[0]
+}
+
+fn handle_line(line: &Line) -> Result<usize, SolveError> {
+    count_combs(&line.states, &line.nums).ok_or_else(SolveError::overflow)
+}
+
+pub fn part1(lines_v: &[Line]) -> Result<usize, SolveError> {
+    let out: Vec<usize> = lines_v.iter().map(handle_line).try_collect()?;
+    out.into_iter().checked_sum()
+}
+
+pub fn part2(lines_v: &[Line]) -> Result<usize, SolveError> {
+    let unfolded = lines_v
+        .iter()
+        .map(|ln| {
+            let nums = ln.nums.repeat(5);
+            let states =
+                Itertools::intersperse((0..5).map(|_| ln.states.clone()), vec![State::Unknown])
+                    .flatten()
+                    .collect_vec();
+            Line { nums, states }
+        })
+        .collect_vec();
+    let out: Vec<usize> = unfolded.iter().map(handle_line).try_collect()?;
+    out.into_iter().checked_sum()
+}
+
+pub struct Day12;
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const DIR: &'static str = CRATE_DIR;
+
+    type Model = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(lines_v: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(lines_v)
+    }
+    fn part2(lines_v: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(lines_v)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    /// Try every way of filling in the unknowns
+    fn count_by_brute_force(states: &[State], nums: &[usize]) -> usize {
+        let unknowns = states
+            .iter()
+            .positions(|s| *s == State::Unknown)
+            .collect_vec();
+        (0..1_usize << unknowns.len())
+            .filter(|bits| {
+                let mut filled = states.to_vec();
+                for (bit, &i) in unknowns.iter().enumerate() {
+                    filled[i] = if bits & (1 << bit) != 0 {
+                        State::Broken
+                    } else {
+                        State::Normal
+                    };
+                }
+                let groups = filled
+                    .split(|s| *s == State::Normal)
+                    .filter(|g| !g.is_empty())
+                    .map(|g| g.len())
+                    .collect_vec();
+                groups == nums
+            })
+            .count()
+    }
+
+    quickcheck! {
+        fn combs_match_brute_force(states: Vec<u8>, nums: Vec<u8>) -> bool {
+            let states = states
+                .iter()
+                .take(14)
+                .map(|s| [State::Normal, State::Broken, State::Unknown][*s as usize % 3])
+                .collect_vec();
+            let nums = nums.iter().take(5).map(|n| *n as usize % 4 + 1).collect_vec();
+            let line = Line { states, nums };
+            handle_line(&line) == Ok(count_by_brute_force(&line.states, &line.nums))
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use aoc_common::Rng;
+
+type Pattern = Vec<Vec<bool>>;
+
+fn transpose(pattern: &Pattern) -> Pattern {
+    (0..pattern[0].len())
+        .map(|j| pattern.iter().map(|row| row[j]).collect())
+        .collect()
+}
+
+/// Number of tiles that differ from their reflection in the line after row `after`
+fn n_mismatched(pattern: &Pattern, after: usize) -> usize {
+    (0..=after)
+        .rev()
+        .zip(after + 1..pattern.len())
+        .map(|(i, j)| {
+            (0..pattern[i].len())
+                .filter(|&x| pattern[i][x] != pattern[j][x])
+                .count()
+        })
+        .sum()
+}
+
+/// How many lines (horizontal and vertical) have 0 and 1 mismatched tiles
+fn count_lines(pattern: &Pattern) -> (usize, usize) {
+    let transposed = transpose(pattern);
+    let counts = [pattern, &transposed]
+        .into_iter()
+        .flat_map(|p| (0..p.len() - 1).map(|after| n_mismatched(p, after)));
+    counts.fold((0, 0), |(zero, one), n| {
+        (zero + (n == 0) as usize, one + (n == 1) as usize)
+    })
+}
+
+/// A pattern with exactly one line of reflection (for part 1) and exactly one
+/// other line that has a single smudge (for part 2)
+fn pattern(rng: &mut Rng) -> Pattern {
+    loop {
+        let (h, w) = (rng.range(5..18) as usize, rng.range(5..18) as usize);
+        let mut p: Pattern = (0..h)
+            .map(|_| (0..w).map(|_| rng.chance(0.5)).collect())
+            .collect();
+        // the clean line is vertical (after column `a`) and doesn't reach the last column
+        let a = rng.index((w - 1) / 2);
+        for row in &mut p {
+            for x in 0..=a {
+                row[2 * a + 1 - x] = row[x];
+            }
+        }
+        // the smudged line is horizontal (after row `b`)
+        let b = rng.index(h - 1);
+        for y in 0..=b.min(h - b - 2) {
+            p[b + 1 + y] = p[b - y].clone();
+        }
+        // the smudge is outside the columns reflected by the clean line
+        let x = rng.range(2 * a as u64 + 2..w as u64) as usize;
+        let y = rng.range((b - b.min(h - b - 2)) as u64..b as u64 + 1) as usize;
+        p[y][x] = !p[y][x];
+        if count_lines(&p) == (1, 1) {
+            return if rng.chance(0.5) { transpose(&p) } else { p };
+        }
+    }
+}
+
+/// `size` patterns of 5-17 x 5-17 tiles
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let patterns: Vec<_> = (0..size)
+        .map(|_| {
+            let rows: Vec<String> = pattern(rng)
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&rock| if rock { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            rows.join("\n") + "\n"
+        })
+        .collect();
+    patterns.join("\n")
+}
@@ -0,0 +1,131 @@
+use itertools::Itertools;
+use aoc_common::{BitGrid, FromChar, Grid, InputFormat, ParseError, Rng, Solution, SolveError, SumT, ToChar};
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Ash,
+    Rock
+}
+impl FromChar for Tile {
+    const EXPECTED: &'static str = "'.' or '#'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Ash),
+            '#' => Some(Self::Rock),
+            _ => None
+        }
+    }
+}
+impl ToChar for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Ash => '.',
+            Self::Rock => '#',
+        }
+    }
+}
+/// Number of tiles that are different from their reflection in the line after
+/// `after_line` (it stops counting once it's more than `max`)
+fn count_asymmetry(pattern: &BitGrid, after_line: usize, max: usize) -> usize {
+    let above = (0..=after_line).rev();
+    let below = after_line + 1..pattern.height();
+    let mut n_diffs = 0;
+    for (i_first, i_last) in above.zip(below) {
+        // compares a whole row at a time (64 tiles per word)
+        n_diffs += pattern.count_row_diffs(i_first, i_last);
+        if n_diffs > max { break; }
+    }
+    n_diffs
+}
+
+/// Number of lines above the horizontal line of symmetry that has exactly
+/// `n_smudges` tiles wrong (0 if there isn't one)
+fn get_horiz_symmetry(pattern: &BitGrid, n_smudges: usize) -> usize {
+    // can't have a symmertry after last line
+    let last_idx = pattern.height() - 1;
+    (0..last_idx)
+        .find(|after_line| count_asymmetry(pattern, *after_line, n_smudges) == n_smudges)
+        // after idx 2=> 0,1,2 before => 3 before
+        .map_or(0, |after_line| after_line + 1)
+}
+
+fn get_vertical_symmetry(pattern: &BitGrid, n_smudges: usize) -> usize {
+    // just use the get_horiz with reversed dimensions
+    get_horiz_symmetry(&pattern.transpose(), n_smudges)
+}
+
+fn get_pattern_symmetry(pattern: &BitGrid) -> usize {
+    100 * get_horiz_symmetry(pattern, 0) + get_vertical_symmetry(pattern, 0)
+}
+
+fn parse_pattern(pattern: &[&str]) -> Result<BitGrid, ParseError> {
+    let grid: Grid<Tile> = Grid::parse(pattern.iter().copied())?;
+    Ok(BitGrid::from_grid(&grid, |t| *t == Tile::Rock))
+}
+
+/// The patterns as where the rocks are
+pub fn parse(contents: &str) -> Result<Vec<BitGrid>, ParseError> {
+    contents
+        .split("\n\n")
+        .map(|pattern| pattern.lines().collect_vec())
+        .filter(|pattern| !pattern.is_empty())
+        .map(|pattern| parse_pattern(&pattern))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(contents))
+}
+
+pub fn part1(patterns: &[BitGrid]) -> usize {
+    let patterns_result = patterns
+        .iter()
+        .map(get_pattern_symmetry);
+    patterns_result.sumt()
+}
+
+fn get_symmetry_2(pattern: &BitGrid) -> Result<usize, SolveError> {
+    // fixing the smudge makes a line that is off by exactly one tile into a
+    // line of symmetry (and the old line can't be one any more as it was off by 0)
+    let horiz = get_horiz_symmetry(pattern, 1);
+    if horiz != 0 {
+        return Ok(100 * horiz);
+    }
+    let vert = get_vertical_symmetry(pattern, 1);
+    if vert != 0 {
+        return Ok(vert);
+    }
+    Err(SolveError::new("a pattern has no 2nd line of symmetry"))
+}
+
+pub fn part2(patterns: &[BitGrid]) -> Result<usize, SolveError> {
+    let patterns_out = patterns.iter().map(get_symmetry_2);
+    patterns_out.sum()
+}
+
+pub struct Day13;
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const DIR: &'static str = CRATE_DIR;
+    const INPUT_FORMAT: InputFormat = InputFormat::GRID.blocks();
+
+    type Model = Vec<BitGrid>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(patterns: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(patterns))
+    }
+    fn part2(patterns: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(patterns)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_common::Rng;
+
+/// A `size` x `size` platform with about 1 in 5 tiles a round rock and 1 in 6 a cube rock
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::with_capacity((size + 1) * size);
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            out.push(match rng.below(30) {
+                0..=5 => 'O',
+                6..=10 => '#',
+                _ => '.',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,136 @@
+use aoc_common::{find_cycle_hashed, BitGrid, Dirn, FromChar, Grid, InputFormat, ParseError, Pos, Rng, Solution, SolveError, SumT, ToChar};
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty, Stationary, Moving
+}
+impl FromChar for Tile {
+    const EXPECTED: &'static str = "'.', '#' or 'O'";
+
+    fn from_char(c: char) -> Option<Self> {
+        use Tile::*;
+        match c {
+            '.' => Some(Empty),
+            '#' => Some(Stationary),
+            'O' => Some(Moving),
+            _ => None
+        }
+    }
+}
+impl ToChar for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Stationary => '#',
+            Tile::Moving => 'O',
+        }
+    }
+}
+/// Where the rocks are on the platform
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    /// The cube-shaped rocks (`#`), which stay where they are
+    pub stationary: BitGrid,
+    /// The rounded rocks (`O`), which roll when the platform is tilted
+    pub moving: BitGrid,
+}
+impl Platform {
+    fn to_grid(&self) -> Grid<Tile> {
+        Grid::from_fn(self.moving.width(), self.moving.height(), |lni, xi| {
+            let pos = Pos::new(lni, xi);
+            if self.stationary.get(pos) {
+                Tile::Stationary
+            } else if self.moving.get(pos) {
+                Tile::Moving
+            } else {
+                Tile::Empty
+            }
+        })
+    }
+}
+
+pub fn parse(contents: &str) -> Result<Platform, ParseError> {
+    let grid: Grid<Tile> = Grid::parse(contents.lines())
+        .map_err(|e| e.locate(contents))?;
+    if grid.is_empty() {
+        return Err(ParseError::at_end(contents, "a grid of rocks"));
+    }
+    Ok(Platform {
+        stationary: BitGrid::from_grid(&grid, |t| *t == Tile::Stationary),
+        moving: BitGrid::from_grid(&grid, |t| *t == Tile::Moving),
+    })
+}
+
+/// Where the moving rocks end up when they all roll as far as they can in `dirn`
+fn tilt(platform: &Platform, dirn: Dirn) -> BitGrid {
+    let mut moving = platform.moving.clone();
+    loop {
+        let empty = !&(&platform.stationary | &moving);
+        // the rocks that have an empty tile next to them in `dirn` move 1 tile
+        // (and the ones behind them can move next time round)
+        let can_move = &moving & &empty.shift(dirn.opp());
+        if can_move.count_ones() == 0 {
+            return moving;
+        }
+        moving = &moving.and_not(&can_move) | &can_move.shift(dirn);
+    }
+}
+
+/// Each moving rock weighs as much as how many rows it is from the south edge
+fn get_load(moving: &BitGrid) -> usize {
+    let height = moving.height();
+    (0..height).map(|lni| (height - lni) * moving.row_count_ones(lni)).sumt()
+}
+
+pub fn part1(platform: &Platform) -> usize {
+    let tilted = Platform { moving: tilt(platform, Dirn::N), ..platform.clone() };
+    aoc_common::trace!("after falling north:\n{}", tilted.to_grid());
+    get_load(&tilted.moving)
+}
+
+/// Tilt north, then west, then south, then east
+fn spin_cycle(platform: &Platform) -> Platform {
+    [Dirn::N, Dirn::W, Dirn::S, Dirn::E].into_iter().fold(platform.clone(), |p, dirn| {
+        Platform { moving: tilt(&p, dirn), ..p }
+    })
+}
+
+const N_SPIN_CYCLES: u64 = 1_000_000_000;
+
+pub fn part2(platform: &Platform) -> usize {
+    // the rocks soon end up going round the same positions so skip to where they are at the end
+    let cycle = find_cycle_hashed(platform.clone(), spin_cycle);
+    aoc_common::debug!("repeats every {} spin cycles after the first {}", cycle.cycle_len, cycle.prefix_len);
+    let end = cycle.nth(N_SPIN_CYCLES);
+    aoc_common::trace!("after {N_SPIN_CYCLES} spin cycles:\n{}", end.to_grid());
+    get_load(&end.moving)
+}
+
+pub struct Day14;
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const DIR: &'static str = CRATE_DIR;
+    const INPUT_FORMAT: InputFormat = InputFormat::GRID;
+
+    type Model = Platform;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(grid: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(grid))
+    }
+    fn part2(grid: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(grid))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
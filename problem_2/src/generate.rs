@@ -0,0 +1,24 @@
+use aoc_common::Rng;
+
+const COLORS: &[&str] = &["red", "green", "blue"];
+
+/// `size` games of 1-6 rounds each
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size {
+        let rounds: Vec<_> = (0..rng.range(1..7))
+            .map(|_| {
+                let mut colors = COLORS.to_vec();
+                rng.shuffle(&mut colors);
+                colors.truncate(rng.range(1..4) as usize);
+                let items: Vec<_> = colors
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..21)))
+                    .collect();
+                items.join(", ")
+            })
+            .collect();
+        out.push_str(&format!("Game {id}: {}\n", rounds.join("; ")));
+    }
+    out
+}
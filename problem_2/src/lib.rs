@@ -0,0 +1,177 @@
+use aoc_common::parse::{split_once, strip_prefix};
+use aoc_common::{parse_item, CheckedSum, ParseError, Rng, Solution, SolveError};
+use std::ops::Add;
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CubeCount {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+impl CubeCount {
+    pub fn just_red(red: u32) -> CubeCount {
+        CubeCount {
+            red,
+            green: 0,
+            blue: 0,
+        }
+    }
+    pub fn just_green(green: u32) -> CubeCount {
+        CubeCount {
+            red: 0,
+            green,
+            blue: 0,
+        }
+    }
+    pub fn just_blue(blue: u32) -> CubeCount {
+        CubeCount {
+            red: 0,
+            green: 0,
+            blue,
+        }
+    }
+    pub fn le(self, other: Self) -> bool {
+        self.red <= other.red && self.green <= other.green && self.blue <= other.blue
+    }
+    pub fn max_cubes(self, other: Self) -> Self {
+        CubeCount {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+    /// `None` if it doesn't fit in a u32
+    pub fn power(self) -> Option<u32> {
+        self.red.checked_mul(self.green)?.checked_mul(self.blue)
+    }
+}
+impl Add for CubeCount {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        CubeCount {
+            red: self.red + other.red,
+            green: self.green + other.green,
+            blue: self.blue + other.blue,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub id: usize,
+    pub rounds: Vec<CubeCount>,
+}
+
+pub fn parse(contents: &str) -> Result<Vec<Game>, ParseError> {
+    contents
+        .lines()
+        .map(parse_game)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(contents))
+}
+
+fn parse_game(ln: &str) -> Result<Game, ParseError> {
+    let ln_without_prefix = strip_prefix(ln, "Game ")?;
+    let (id_str, rest) = split_once(ln_without_prefix, ":")?;
+    let id = parse_item(id_str.trim())?;
+    let rounds = rest
+        .trim()
+        .split_terminator(';')
+        .map(cubes_in_round)
+        .collect::<Result<Vec<_>, _>>()?;
+    if rounds.is_empty() {
+        return Err(ParseError::new(rest, "at least 1 round"));
+    }
+    Ok(Game { id, rounds })
+}
+
+fn cubes_in_round(round: &str) -> Result<CubeCount, ParseError> {
+    let items = round.trim().split(',').map(|item| item.trim());
+    let mut seen_colors: Vec<&str> = Vec::with_capacity(3);
+    let mut cube_counts = items.map(|item_s| {
+        let (amount_s, color) = item_s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(item_s, "'<amount> <color>'"))?;
+        let amount = parse_item::<u32>(amount_s)?;
+        if seen_colors.contains(&color) {
+            return Err(ParseError::new(
+                color,
+                "a color that isn't already in this round",
+            ));
+        }
+        seen_colors.push(color);
+        match color {
+            "red" => Ok(CubeCount::just_red(amount)),
+            "green" => Ok(CubeCount::just_green(amount)),
+            "blue" => Ok(CubeCount::just_blue(amount)),
+            _ => Err(ParseError::new(color, "a color (red, green or blue)")),
+        }
+    });
+    // split() always gives at least 1 item so this is never just the default
+    cube_counts.try_fold(CubeCount::default(), |total, count| Ok(total + count?))
+}
+
+pub fn part1(games: &[Game]) -> Result<usize, SolveError> {
+    let cubes_in_bag = CubeCount {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+    let ids = games.iter().filter_map(|game| -> Option<usize> {
+        let count = game.rounds.iter().copied().reduce(CubeCount::max_cubes)?;
+        aoc_common::debug!("Game {} => {count:?}", game.id);
+        if count.le(cubes_in_bag) {
+            Some(game.id)
+        } else {
+            None
+        }
+    });
+    ids.checked_sum()
+}
+
+pub fn part2(games: &[Game]) -> Result<u32, SolveError> {
+    let powers = games.iter().map(|game| -> Option<u32> {
+        let required_cubes = game
+            .rounds
+            .iter()
+            .copied()
+            .reduce(CubeCount::max_cubes)
+            .unwrap_or_default();
+        let power = required_cubes.power();
+        aoc_common::debug!("Game {} => {required_cubes:?}, power={power:?}", game.id);
+        power
+    });
+    let powers: Vec<_> = powers
+        .collect::<Option<_>>()
+        .ok_or_else(SolveError::overflow)?;
+    powers.into_iter().checked_sum()
+}
+
+pub struct Day2;
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const DIR: &'static str = CRATE_DIR;
+
+    type Model = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(games: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(games)
+    }
+    fn part2(games: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(games)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
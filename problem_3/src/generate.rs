@@ -0,0 +1,30 @@
+use aoc_common::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+/// A `size` x `size` schematic of numbers (up to 3 digits) and symbols
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = Vec::with_capacity(size);
+        while line.len() < size {
+            match rng.below(10) {
+                0 => line.push(*rng.choose(SYMBOLS)),
+                1 | 2 => {
+                    // always followed by a '.' so that numbers don't run together
+                    line.extend(rng.range(1..1000).to_string().bytes());
+                    line.push(b'.');
+                }
+                _ => line.push(b'.'),
+            }
+        }
+        line.truncate(size);
+        if line.last().is_some_and(u8::is_ascii_digit) {
+            *line.last_mut().unwrap() = b'.';
+        }
+        out.push_str(std::str::from_utf8(&line).unwrap());
+        out.push('\n');
+    }
+    out
+}
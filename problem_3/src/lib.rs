@@ -0,0 +1,140 @@
+use itertools::Itertools;
+use aoc_common::{parse_item, CheckedSum, InputFormat, Interval, ParseError, Pos, Rng, Solution, SolveError};
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// The lines of the schematic. They are checked to be ASCII (so that byte and
+/// char indices are the same) and every number in them to fit in a u32
+pub fn parse(contents: &str) -> Result<Vec<String>, ParseError> {
+    let lines: Vec<String> = contents.lines()
+        .map(|ln| {
+            if let Some((i, c)) = ln.char_indices().find(|(_i, c)| !c.is_ascii()) {
+                return Err(ParseError::new(&ln[i..i + c.len_utf8()], "an ASCII character"));
+            }
+            for num_s in ln.split(|c: char| !c.is_numeric()).filter(|s| !s.is_empty()) {
+                parse_item::<u32>(num_s)?;
+            }
+            Ok(ln.to_string())
+        })
+        .collect::<Result<_, ParseError>>()
+        .map_err(|e| e.locate(contents))?;
+    if lines.is_empty() {
+        return Err(ParseError::at_end(contents, "a line of the schematic"));
+    }
+    Ok(lines)
+}
+
+/// Size of the schematic, for the bounds checks (the lines can be different lengths)
+fn schematic_size(lines: &[String]) -> Pos {
+    let maxlen = lines.iter().map(|ln| ln.len()).max().expect("parse checks there is a line");
+    Pos::new(lines.len(), maxlen)
+}
+
+/// The char at `pos` ('.' past the end of a short line)
+fn char_at(lines: &[String], pos: Pos) -> char {
+    lines[pos.row].as_bytes().get(pos.col).map_or('.', |b| *b as char)
+}
+
+/// The numbers on a line as (the columns it spans, number)
+fn find_nums_on_line(ln: &str) -> Vec<(Interval<usize>, u32)> {
+    let numeric_groups = ln
+        .char_indices()
+        .group_by(|(_i, c)| c.is_numeric());
+    let nums_on_this_line = numeric_groups.into_iter()
+        .filter_map(|(is_num, val)| if is_num { Some(val) } else { None })
+        .map(|groups| {
+            let groupsv = groups.collect_vec();
+            aoc_common::trace!("{:?}", groupsv);
+            let first_idx = groupsv.first().unwrap().0;
+            let last_idx = groupsv.last().unwrap().0;
+            let num_str = groupsv.iter().map(|x| x.1).join("");
+            let num = num_str.parse::<u32>().expect("Invalid num format");
+            (Interval::from_incl(first_idx, last_idx), num)
+        });
+    nums_on_this_line.collect()
+}
+
+pub fn part1(lines: &[String]) -> Result<u32, SolveError> {
+    let size = schematic_size(lines);
+    let sums = lines.iter().enumerate().map(|(li, ln)| {
+        let nums_on_this_line = find_nums_on_line(ln).into_iter()
+            .filter_map(|(span, num)| {
+                // everything around the number (incl. diagonal) but not the number itself
+                let adj = (span.start..span.end)
+                    .flat_map(|i| Pos::new(li, i).neighbours8(size))
+                    .filter(|p| !(p.row == li && span.contains(p.col)))
+                    .unique()
+                    .map(|p| char_at(lines, p))
+                    .collect_vec();
+                aoc_common::trace!("  adjacent to {num}: {:?}", adj);
+                if adj.iter().any(|c| *c != '.') {
+                    // num should be counted
+                    Some(num)
+                } else {
+                    None
+                }
+            });
+        let x = nums_on_this_line.checked_sum();
+        aoc_common::debug!("x={x:?}");
+        x
+    });
+    sums.collect::<Result<Vec<_>, _>>()?.into_iter().checked_sum()
+}
+
+/// The number that `pos` is part of as (line, columns, number), if there is one
+fn find_num_at(num_idx_list: &[Vec<(Interval<usize>, u32)>], pos: Pos) -> Option<(usize, Interval<usize>, u32)> {
+    num_idx_list[pos.row].iter()
+        .find(|(span, _num)| span.contains(pos.col))
+        .map(|(span, num)| (pos.row, *span, *num))
+}
+
+pub fn part2(lines: &[String]) -> Result<u32, SolveError> {
+    let size = schematic_size(lines);
+    let num_idx_list: Vec<_> = lines.iter().map(|ln| find_nums_on_line(ln)).collect();
+    aoc_common::trace!("{:#?}", num_idx_list);
+    let ratios_on_lines = lines.iter().enumerate().map(|(li, ln)| {
+        let star_indices = ln
+            .char_indices()
+            .filter_map(|(i, c)| if c == '*' { Some(i) } else { None });
+        let ratios_list = star_indices.filter_map(|i| {
+            aoc_common::debug!("Star: {}:{}", li, i);
+            // a number that touches the star in more than one place only counts once
+            let adj_nums = Pos::new(li, i).neighbours8(size)
+                .filter_map(|p| find_num_at(&num_idx_list, p))
+                .unique()
+                .map(|(_li, _span, num)| num)
+                .collect_vec();
+            aoc_common::debug!("  {adj_nums:?}");
+            (adj_nums.len() == 2).then(|| adj_nums[0].checked_mul(adj_nums[1]).ok_or_else(SolveError::overflow))
+        });
+        ratios_list.collect::<Result<Vec<_>, _>>()?.into_iter().checked_sum()
+    });
+    ratios_on_lines.collect::<Result<Vec<_>, _>>()?.into_iter().checked_sum()
+}
+
+pub struct Day3;
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const DIR: &'static str = CRATE_DIR;
+    const INPUT_FORMAT: InputFormat = InputFormat::GRID;
+
+    type Model = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(lines: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(lines)
+    }
+    fn part2(lines: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(lines)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
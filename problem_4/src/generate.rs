@@ -0,0 +1,31 @@
+use aoc_common::Rng;
+
+/// `size` cards with 10 winning numbers and 25 chosen ones.
+/// Most cards have no matches so that the number of copies in part 2 stays small.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size {
+        let mut nums: Vec<u64> = (1..100).collect();
+        rng.shuffle(&mut nums);
+        let winning = &nums[..10];
+        let mut chosen = nums[10..35].to_vec();
+        let max_matching = (size - id).min(4);
+        let n_matching = if rng.chance(0.7) {
+            0
+        } else {
+            rng.index(max_matching + 1)
+        };
+        chosen[..n_matching].copy_from_slice(&winning[..n_matching]);
+        rng.shuffle(&mut chosen);
+        let fmt_nums = |nums: &[u64]| {
+            let nums: Vec<_> = nums.iter().map(|n| format!("{n:>2}")).collect();
+            nums.join(" ")
+        };
+        out.push_str(&format!(
+            "Card {id:>3}: {} | {}\n",
+            fmt_nums(winning),
+            fmt_nums(&chosen)
+        ));
+    }
+    out
+}
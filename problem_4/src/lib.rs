@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+// use std::iter;
+use itertools::Itertools;
+use num::BigUint;
+use aoc_common::parse::{split_once, strip_prefix};
+use aoc_common::{parse_ws_list, CheckedSum, ParseError, Rng, Solution, SolveError};
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    pub winning: Vec<u32>,
+    pub chosen: Vec<u32>,
+}
+impl Card {
+    fn n_matching(&self) -> usize {
+        let win_set = self.winning.iter().collect::<HashSet<_>>();
+        let chosen_set = self.chosen.iter().collect::<HashSet<_>>();
+        win_set.intersection(&chosen_set).count()
+    }
+}
+
+fn parse_card(ln: &str) -> Result<Card, ParseError> {
+    let without_card = strip_prefix(ln, "Card ")?.trim();
+    let (_id_str, main_line) = split_once(without_card, ":")?;
+    let main_line = main_line.trim();
+    let (winning_s, chosen_s) = split_once(main_line, "|")?;
+    Ok(Card {
+        winning: parse_ws_list(winning_s).try_collect()?,
+        chosen: parse_ws_list(chosen_s).try_collect()?,
+    })
+}
+
+pub fn parse(contents: &str) -> Result<Vec<Card>, ParseError> {
+    contents.lines()
+        .map(parse_card)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(contents))
+}
+
+pub fn part1(cards: &[Card]) -> Result<u128, SolveError> {
+    let won_amounts = cards.iter().map(|card| {
+        let n_matching = card.n_matching();
+        if n_matching == 0 { Some(0) } else { 1_u128.checked_shl(n_matching as u32 - 1) }
+    });
+    let won_amounts: Vec<_> = won_amounts.collect::<Option<_>>().ok_or_else(SolveError::overflow)?;
+    won_amounts.into_iter().checked_sum()
+}
+
+/// The number of copies can double with every card so they are counted in a `BigUint`
+pub fn part2(cards: &[Card]) -> Result<BigUint, SolveError> {
+    let n_matching = cards.iter().map(Card::n_matching).collect_vec();
+    let n_lines = n_matching.len();
+    let mut amounts = vec![BigUint::from(1_u8); n_lines];
+    for i in 0..n_lines {
+        let curr_amount = amounts[i].clone();
+        let curr_matching = n_matching[i];
+        let won = amounts.get_mut(i+1..i+1+curr_matching).ok_or_else(|| {
+            SolveError::new(format!("card {} wins copies of cards past the end of the table", i + 1))
+        })?;
+        for amount in won {  // the next <amount matched> cards...
+            // ... each get +1*<amount we have curr card>
+            *amount += &curr_amount;
+        }
+    }
+    amounts.into_iter().checked_sum()
+}
+
+pub struct Day4;
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const DIR: &'static str = CRATE_DIR;
+
+    type Model = Vec<Card>;
+    type Answer1 = u128;
+    type Answer2 = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(cards: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(cards)
+    }
+    fn part2(cards: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(cards)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::BTreeSet;
+
+use aoc_common::Rng;
+
+const MAP_NAMES: &[&str] = &[
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+const MAX: u64 = 1 << 32;
+
+/// 10 seed ranges and 7 maps of `size` lines each (the source ranges in a map don't overlap)
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let seeds: Vec<_> = (0..10)
+        .map(|_| format!("{} {}", rng.below(MAX), rng.range(1..1 << 28)))
+        .collect();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAP_NAMES {
+        out.push_str(&format!("\n{name} map:\n"));
+        let mut bounds = BTreeSet::new();
+        while bounds.len() < 2 * size.max(1) {
+            bounds.insert(rng.below(MAX));
+        }
+        let bounds: Vec<_> = bounds.into_iter().collect();
+        let mut lines: Vec<_> = bounds
+            .chunks(2)
+            .map(|r| {
+                let len = r[1] - r[0];
+                format!("{} {} {len}", rng.below(MAX - len), r[0])
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+        for ln in lines {
+            out.push_str(&ln);
+            out.push('\n');
+        }
+    }
+    out
+}
@@ -0,0 +1,330 @@
+use aoc_common::parse::strip_prefix;
+use aoc_common::{
+    parse_ws_list, InputFormat, Interval, IntervalSet, ParseError, Rng, Solution, SolveError,
+};
+use itertools::Itertools;
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+// This file uses u64 throughout as the input has some numbers up to 2^32-1 and I don't want signed/unsigned to become an issue
+
+#[derive(Debug, Clone, Copy)]
+struct MapLine {
+    pub dest_start: u64,
+    pub src_start: u64,
+    pub range_len: u64,
+}
+// tuple ctor
+impl MapLine {
+    pub fn from_tuple((dest_start, src_start, range_len): (u64, u64, u64)) -> MapLine {
+        MapLine {
+            dest_start,
+            src_start,
+            range_len,
+        }
+    }
+}
+// convenience one-line getters
+#[allow(dead_code)]
+impl MapLine {
+    pub fn get_src_end_excl(&self) -> u64 {
+        self.src_start + self.range_len
+    }
+    pub fn get_dest_end_excl(&self) -> u64 {
+        self.dest_start + self.range_len
+    }
+    pub fn get_src_end_incl(&self) -> u64 {
+        self.get_src_end_excl() - 1
+    }
+    pub fn get_dest_end_incl(&self) -> u64 {
+        self.get_dest_end_excl() - 1
+    }
+    pub fn get_src_range_excl(&self) -> (u64, u64) {
+        (self.src_start, self.get_src_end_excl())
+    }
+    pub fn get_dest_range_excl(&self) -> (u64, u64) {
+        (self.dest_start, self.get_dest_end_excl())
+    }
+    pub fn get_src_range_incl(&self) -> (u64, u64) {
+        (self.src_start, self.get_src_end_incl())
+    }
+    pub fn get_dest_range_incl(&self) -> (u64, u64) {
+        (self.dest_start, self.get_dest_end_incl())
+    }
+    pub fn get_src_range(&self) -> Interval<u64> {
+        Interval::from_len(self.src_start, self.range_len)
+    }
+    pub fn get_dest_range(&self) -> Interval<u64> {
+        Interval::from_len(self.dest_start, self.range_len)
+    }
+}
+// apply line
+impl MapLine {
+    fn src_contains(&self, num: u64) -> bool {
+        self.get_src_range().contains(num)
+    }
+
+    fn apply_line(&self, num: u64) -> Option<u64> {
+        self.src_contains(num).then(|| {
+            let offset = num - self.src_start;
+            self.dest_start + offset
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FullMap {
+    lines: Vec<MapLine>,
+}
+impl FullMap {
+    fn new(lines: Vec<MapLine>) -> Self {
+        FullMap { lines }
+    }
+
+    fn apply_map(&self, num: u64) -> u64 {
+        // try apply each of them, do first one that succeeds, if none work, keep it same
+        self.lines
+            .iter()
+            .find_map(|ln| ln.apply_line(num))
+            .unwrap_or(num)
+    }
+
+    fn apply_map_r(&self, rlist: Vec<Interval<u64>>) -> Vec<Interval<u64>> {
+        // each range is done separately so that the numbers in overlapping ranges are kept (and counted) twice
+        let r = rlist
+            .into_iter()
+            .flat_map(|r| {
+                // the first line that contains a number maps it so each line only gets what the previous ones left
+                let mut unmapped = IntervalSet::from(r);
+                let mut mapped = Vec::new();
+                for ln in &self.lines {
+                    let src = IntervalSet::from(ln.get_src_range());
+                    mapped.extend(
+                        unmapped
+                            .intersection(&src)
+                            .iter()
+                            .map(|part| part.translate(ln.src_start, ln.dest_start)),
+                    );
+                    unmapped = unmapped.difference(&src);
+                }
+                // no line matching = no-op
+                unmapped.iter().chain(mapped).collect_vec()
+            })
+            .collect_vec();
+        aoc_common::trace!("{:#?}", r);
+        r
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MapsData {
+    maps: Vec<FullMap>,
+}
+impl MapsData {
+    fn new(maps: Vec<FullMap>) -> Self {
+        Self { maps }
+    }
+
+    fn apply_maps(&self, num: u64) -> u64 {
+        self.maps.iter().fold(num, |prev, mp| mp.apply_map(prev))
+    }
+
+    fn apply_maps_r(&self, rlist: Vec<Interval<u64>>) -> Vec<Interval<u64>> {
+        self.maps
+            .iter()
+            .fold(rlist, |prev, mp| mp.apply_map_r(prev))
+    }
+}
+
+/// The ranges are checked to be non-empty and to end before `u64::MAX`
+/// so that none of the range arithmetic can overflow
+fn parse_map_line(line: &str) -> Result<MapLine, ParseError> {
+    let nums: Vec<u64> = parse_ws_list(line).try_collect()?;
+    match nums[..] {
+        [_, _, 0] => Err(ParseError::new(line, "a range length of at least 1")),
+        [dest_start, src_start, range_len]
+            if dest_start.max(src_start).checked_add(range_len).is_none() =>
+        {
+            Err(ParseError::new(line, "ranges that end before 2^64"))
+        }
+        [dest_start, src_start, range_len] => {
+            Ok(MapLine::from_tuple((dest_start, src_start, range_len)))
+        }
+        _ => Err(ParseError::new(line, "3 numbers")),
+    }
+}
+
+/// Each map is a block of lines starting with its name
+fn parse_maps<'a>(blocks: impl Iterator<Item = Vec<&'a str>>) -> Result<MapsData, ParseError> {
+    let maps = blocks
+        .map(|block| {
+            let (name, map_lines) = block
+                .split_first()
+                .expect("Empty blocks should've been filtered out");
+            if name.starts_with(|c: char| c.is_numeric()) {
+                return Err(ParseError::new(name, "the name of a map"));
+            }
+            Ok(FullMap::new(
+                map_lines
+                    .iter()
+                    .map(|ln| parse_map_line(ln))
+                    .try_collect()?,
+            ))
+        })
+        .try_collect()?;
+    Ok(MapsData::new(maps))
+}
+
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    maps: MapsData,
+}
+
+pub fn parse(contents: &str) -> Result<Almanac, ParseError> {
+    let mut blocks = contents
+        .split("\n\n")
+        .map(|block| block.lines().collect_vec())
+        .filter(|block| !block.is_empty());
+    let mut parse_blocks = || {
+        let seeds_block = blocks
+            .next()
+            .ok_or_else(|| ParseError::at_end(contents, "'seeds: ...'"))?;
+        let seeds = parse_ws_list(strip_prefix(seeds_block[0], "seeds: ")?).try_collect()?;
+        if let Some(line) = seeds_block.get(1) {
+            return Err(ParseError::new(line, "a blank line before the first map"));
+        }
+        let maps = parse_maps(&mut blocks)?;
+        Ok(Almanac { seeds, maps })
+    };
+    parse_blocks().map_err(|e: ParseError| e.locate(contents))
+}
+
+fn no_seeds() -> SolveError {
+    SolveError::new("there are no seeds")
+}
+
+pub fn part1(almanac: &Almanac) -> Result<u64, SolveError> {
+    let seed_loc_v = almanac
+        .seeds
+        .iter()
+        .map(|s| (*s, almanac.maps.apply_maps(*s)))
+        .collect_vec();
+    let min_pair = seed_loc_v
+        .iter()
+        .min_by_key(|(_sd, loc)| loc)
+        .ok_or_else(no_seeds)?;
+    Ok(min_pair.1)
+}
+
+/// The (non-empty) ranges of seeds, `seeds` being pairs of (start, length)
+fn seed_ranges_part2(seeds: &[u64]) -> Result<Vec<Interval<u64>>, SolveError> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(SolveError::new(
+            "the seeds should be pairs of (start, length)",
+        ));
+    }
+    seeds
+        .iter()
+        .tuples()
+        .filter(|(_start, len)| **len > 0)
+        .map(|(start, len)| {
+            start.checked_add(*len).ok_or_else(SolveError::overflow)?;
+            Ok(Interval::from_len(*start, *len))
+        })
+        .collect()
+}
+
+pub fn part2(almanac: &Almanac) -> Result<u64, SolveError> {
+    let seeds_v = seed_ranges_part2(&almanac.seeds)?;
+    let maps = &almanac.maps;
+    let seed_loc_v = seeds_v
+        .iter()
+        .map(|s| (s.to_owned(), maps.apply_maps_r(vec![s.to_owned()])))
+        .collect_vec();
+    aoc_common::debug!("{:#?}; \n\n {:#?}", seeds_v, seed_loc_v);
+    // TODO this will work but only because we don't need orig thing only result
+    let min_value = seed_loc_v
+        .iter()
+        .map(|(_src, dest)| {
+            // start is, by definition, the min of a range
+            dest.iter()
+                .min_by_key(|r| r.start)
+                .expect("Expected non-zero dest")
+                .start
+        })
+        .min()
+        .ok_or_else(no_seeds)?;
+    Ok(min_value)
+}
+
+pub struct Day5;
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const DIR: &'static str = CRATE_DIR;
+    const INPUT_FORMAT: InputFormat = InputFormat::LINES.blocks();
+
+    type Model = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(almanac: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(almanac)
+    }
+    fn part2(almanac: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(almanac)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    /// At most 7 maps of 10 lines (like the real input but smaller) so that the test is quick
+    fn make_maps(maps: &[Vec<(u8, u8, u8)>]) -> MapsData {
+        MapsData::new(
+            maps.iter()
+                .take(7)
+                .map(|lines| {
+                    FullMap::new(
+                        lines
+                            .iter()
+                            .take(10)
+                            .map(|&(dest, src, len)| {
+                                MapLine::from_tuple((dest as u64, src as u64, len as u64 % 16 + 1))
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    quickcheck! {
+        // mapping a range should give the same numbers as mapping each number in it
+        fn ranges_map_like_each_number(seed_ranges: Vec<(u8, u8)>, maps: Vec<Vec<(u8, u8, u8)>>) -> bool {
+            let maps = make_maps(&maps);
+            let ranges = seed_ranges.iter().map(|&(start, len)| Interval::from_len(start as u64, len as u64 % 16 + 1)).collect_vec();
+            let each_number = ranges.iter()
+                .flat_map(|r| (r.start..r.end).map(|n| maps.apply_maps(n)))
+                .sorted()
+                .collect_vec();
+            let from_ranges = maps.apply_maps_r(ranges)
+                .into_iter()
+                .flat_map(|r| r.start..r.end)
+                .sorted()
+                .collect_vec();
+            each_number == from_ranges
+        }
+    }
+}
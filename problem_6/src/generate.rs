@@ -0,0 +1,27 @@
+use aoc_common::Rng;
+
+/// `size` races (at most 9, so that the numbers in part 2 fit in a u64).
+/// Each record has twice as many digits as its time so that part 2 has a
+/// solution too.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n_races = size.clamp(1, 9);
+    let digits = (9 / n_races) as u32;
+    let (times, dists): (Vec<_>, Vec<_>) = (0..n_races)
+        .map(|_| {
+            // a time >= 0.7 * 10^digits leaves room for a record of 2*digits digits
+            let time = rng.range(7 * 10u64.pow(digits - 1)..10u64.pow(digits));
+            let dist = rng.range(10u64.pow(2 * digits - 1)..time * time / 4 - 1);
+            (time.to_string(), dist.to_string())
+        })
+        .unzip();
+    let width = dists.iter().map(String::len).max().unwrap_or(0);
+    let fmt_nums = |nums: &[String]| {
+        let nums: Vec<_> = nums.iter().map(|n| format!("{n:>width$}")).collect();
+        nums.join(" ")
+    };
+    format!(
+        "Time:     {}\nDistance: {}\n",
+        fmt_nums(&times),
+        fmt_nums(&dists)
+    )
+}
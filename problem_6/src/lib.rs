@@ -0,0 +1,153 @@
+use itertools::Itertools;
+// use std::iter;
+use aoc_common::parse::strip_prefix;
+use aoc_common::{parse_ws_list, CheckedProduct, ParseError, Rng, Solution, SolveError};
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// The range of hold times that win (both inclusive), `None` if there are none
+fn get_race_range((time, record_dist): (u64, u64)) -> Option<(u64, u64)> {
+    // T = `time` = time available for race
+    // S_r = `record_dist`
+    // s = distance travelled
+    // t_h = time held
+    // s = time left * speed accumulated
+    // s = (T - t_h) * t_h
+    // s = (T - t_h) * t_h > S_r
+    // T*t_h - t_h^2 > S_r
+    // t_h^2 - T*t_h + S_r < 0
+    // t_h >= 0 and
+    // [ --T - sqrt((-T)^2 - 4*1*S_r) ] / 2*1 < t_h < [ --T + sqrt((-T)^2 - 4*1*S_r) ] / 2*1
+    // [T - sqrt(T^2 - 4*S_r)] / 2 < t_h < [T + sqrt(T^2 - 4*S_r)]
+    // This is done in u128 (squaring a u64 can overflow it) and with an integer
+    // square root so that it is exact for any input, f64s aren't precise enough.
+    let (time, record_dist) = (time as u128, record_dist as u128);
+    // discr < 0 => no real solutions
+    let discriminant = (time * time).checked_sub(4 * record_dist)?;
+    let wins = |t_h: u128| (time - t_h) * t_h > record_dist;
+    // isqrt() rounds down so this is at most 2 below the first winning t_h
+    let mut lo_incl = (time - discriminant.isqrt()) / 2;
+    while !wins(lo_incl) {
+        if lo_incl >= time / 2 {
+            // got to the top of the parabola without winning
+            return None;
+        }
+        lo_incl += 1;
+    }
+    // the parabola is symmetric about T/2
+    let hi_incl = time - lo_incl;
+    Some((lo_incl as u64, hi_incl as u64))
+}
+
+fn get_race_moe(race: (u64, u64)) -> Result<u64, SolveError> {
+    let (lo_incl, hi_incl) = get_race_range(race).ok_or_else(|| {
+        SolveError::new(format!(
+            "the race of {} ms with a record of {} mm can't be won",
+            race.0, race.1
+        ))
+    })?;
+    Ok(hi_incl - lo_incl + 1)
+}
+
+#[derive(Debug, Clone)]
+pub struct Races {
+    pub times: Vec<u64>,
+    pub dists: Vec<u64>,
+}
+
+fn parse_nums_line(
+    lines: &[&str],
+    i: usize,
+    prefix: &str,
+    contents: &str,
+) -> Result<Vec<u64>, ParseError> {
+    let ln = lines
+        .get(i)
+        .ok_or_else(|| ParseError::at_end(contents, format!("a {prefix:?} line")))?;
+    parse_ws_list(strip_prefix(ln, prefix)?).try_collect()
+}
+
+pub fn parse(contents: &str) -> Result<Races, ParseError> {
+    let lines = contents.lines().collect_vec();
+    let parse_lines = || {
+        let times = parse_nums_line(&lines, 0, "Time: ", contents)?;
+        let dists = parse_nums_line(&lines, 1, "Distance: ", contents)?;
+        if dists.len() != times.len() {
+            return Err(ParseError::new(
+                lines[1],
+                format!("{} distances (1 per race)", times.len()),
+            ));
+        }
+        Ok(Races { times, dists })
+    };
+    parse_lines().map_err(|e| e.locate(contents))
+}
+
+pub fn part1(races: &Races) -> Result<u64, SolveError> {
+    let td_vec = races
+        .times
+        .iter()
+        .copied()
+        .zip(races.dists.iter().copied())
+        .collect_vec();
+    let moe: Vec<_> = td_vec.iter().map(|r| get_race_moe(*r)).try_collect()?;
+    aoc_common::debug!("{:#?};\n {:#?}", td_vec, moe);
+    moe.into_iter().checked_product()
+}
+
+/// The number you get by ignoring the spaces between the numbers
+fn join_digits(nums: &[u64]) -> Result<u64, SolveError> {
+    nums.iter()
+        .join("")
+        .parse::<u64>()
+        .map_err(|_| SolveError::new("the joined numbers should fit in a u64"))
+}
+
+pub fn part2(races: &Races) -> Result<u64, SolveError> {
+    let time = join_digits(&races.times)?;
+    let dist = join_digits(&races.dists)?;
+    get_race_moe((time, dist))
+}
+
+pub struct Day6;
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const DIR: &'static str = CRATE_DIR;
+
+    type Model = Races;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(races: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(races)
+    }
+    fn part2(races: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(races)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::quickcheck;
+
+    quickcheck! {
+        // the closed form should find exactly the hold times that beat the record
+        fn range_is_the_winning_hold_times(time: u16, record: u32) -> bool {
+            let (time, record) = (time as u64, record as u64);
+            let winning = (0..=time).filter(|hold| (time - hold) * hold > record).collect_vec();
+            // (`None` if there's no way to win)
+            let expected = winning.first().copied().zip(winning.last().copied());
+            get_race_range((time, record)) == expected
+        }
+    }
+}
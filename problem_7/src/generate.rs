@@ -0,0 +1,14 @@
+use aoc_common::Rng;
+
+use crate::CARD_CHARS;
+
+/// `size` hands with bids of 1-1000
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let cards = CARD_CHARS.as_bytes();
+    let mut out = String::new();
+    for _ in 0..size {
+        let hand: String = (0..5).map(|_| *rng.choose(cards) as char).collect();
+        out.push_str(&format!("{hand} {}\n", rng.range(1..1001)));
+    }
+    out
+}
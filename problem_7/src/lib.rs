@@ -0,0 +1,152 @@
+use itertools::Itertools;
+use counter::Counter;
+use aoc_common::{parse_item, ParseError, Rng, Solution, SolveError};
+
+mod generate;
+mod part2;
+
+pub use part2::part2;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// value: 2-9 = 2-9;  T=10, J=11, Q=12, K=13, A=14
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Card {
+    value: u8
+}
+impl Card {
+    fn new(value: u8) -> Card {
+        assert!((2..=14).contains(&value));
+        Card {value}
+    }
+}
+
+// here it's very handy that the derive() for the Ord is exactly what we want
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct CardList {
+    cards: Vec<Card>
+}
+impl CardList {
+    fn new(cards: Vec<Card>) -> Self {
+        assert_eq!(cards.len(), 5);
+        Self { cards }
+    }
+
+    fn categorize(&self) -> HandCategory {
+        let cards_set: Counter<_> = self.cards.iter().collect();
+        let most_common = cards_set.most_common();
+        match most_common.as_slice() {
+            [(_, 5), ..] => HandCategory::FiveOfKind,
+            [(_, 4), ..] => HandCategory::FourOfKind,
+            [(_, 3), (_, 2)] => HandCategory::FullHouse,
+            [(_, 3), ..] => HandCategory::ThreeOfKind,
+            [(_, 2), (_, 2), ..] => HandCategory::TwoPair,
+            [(_, 2), ..] => HandCategory::OnePair,
+            [..] => HandCategory::HighCard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum HandCategory {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfKind,
+    FullHouse,
+    FourOfKind,
+    FiveOfKind
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Hand {
+    category: HandCategory,
+    cards: CardList,
+}
+impl Hand {
+    fn from_cards(cards: CardList) -> Self {
+        Self { category: cards.categorize(), cards }
+    }
+}
+
+fn parse_card(c: char) -> Card {
+    Card::new(match c {
+        'T' => 10,
+        'J' => 11,
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        num if ('2'..='9').contains(&num) => num.to_digit(10).unwrap() as u8,
+        _ => unreachable!("cards are checked by parse()")
+    })
+}
+fn parse_hand(s: &str) -> Hand {
+    Hand::from_cards(CardList::new(s.trim().chars().map(parse_card).collect_vec()))
+}
+
+/// A line of the input: the hand as written (always 5 valid cards) and its bid
+#[derive(Debug, Clone)]
+pub struct HandBid {
+    hand: String,
+    bid: u32,
+}
+
+const CARD_CHARS: &str = "23456789TJQKA";
+
+fn parse_line(ln: &str) -> Result<HandBid, ParseError> {
+    let Some((hand_s, bid_s)) = ln.split_whitespace().collect_tuple() else {
+        return Err(ParseError::new(ln, "'<cards> <number>'"));
+    };
+    if let Some((i, c)) = hand_s.char_indices().find(|(_, c)| !CARD_CHARS.contains(*c)) {
+        return Err(ParseError::new(&hand_s[i..i + c.len_utf8()], "a card (2-9, T, J, Q, K or A)"));
+    }
+    if hand_s.len() != 5 {
+        return Err(ParseError::new(hand_s, "5 cards"));
+    }
+    let bid = parse_item(bid_s)?;
+    Ok(HandBid { hand: hand_s.to_string(), bid })
+}
+
+pub fn parse(contents: &str) -> Result<Vec<HandBid>, ParseError> {
+    contents.lines()
+        .map(parse_line)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(contents))
+}
+
+/// In u128 so that it can't overflow (each product is less than 2^64)
+pub fn part1(hands: &[HandBid]) -> u128 {
+    let lines_v = hands.iter().map(|HandBid { hand, bid }| (parse_hand(hand), *bid));
+    let lines_with_rank =  lines_v
+        .sorted()
+        .enumerate()
+        .map(|(rank_0based, v)| (rank_0based + 1, v));
+    let ranks_and_bids = lines_with_rank.map(|(rank, (_hand, bid))| (rank, bid));
+    let products = ranks_and_bids.map(|(rank, bid)| rank as u128 * bid as u128);
+    let s: u128 = products.sum();
+    s
+}
+
+pub struct Day7;
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const DIR: &'static str = CRATE_DIR;
+
+    type Model = Vec<HandBid>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(hands: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(hands))
+    }
+    fn part2(hands: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(hands))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
@@ -2,10 +2,10 @@
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::fmt::Display;
-use std::fs;
 use std::iter;
 use std::str::FromStr;
 use counter::Counter;
+use crate::HandBid;
 
 
 /// value: J(joker)=1, 2-9 = 2-9;  T=10, Q=12, K=13, A=14
@@ -15,7 +15,7 @@ struct Card {
 }
 impl Card {
     fn new(value: u8) -> Card {
-        assert!(1 <= value && value <= 14 && value != 11);
+        assert!((1..=14).contains(&value) && value != 11);
         Card {value}
     }
 }
@@ -37,7 +37,7 @@ impl CardList {
             .iter()
             .enumerate()
             .filter_map(|(i, card)| (card == &Card::new(1)).then_some(i)).collect_vec();
-        if joker_indices.len() == 0 { return self.categorize_simple(); }
+        if joker_indices.is_empty() { return self.categorize_simple(); }
         let card_combos_to_try = (0..joker_indices.len())
             .map(|ji_i| {
                 let ji = joker_indices[ji_i];
@@ -51,8 +51,8 @@ impl CardList {
                 cards_cp[*i] = *sub;
             }
             // determine key
-            let categ = Self::new(cards_cp).categorize_simple();
-            categ  // only care about category as the key (rest is the same no matter how the J is sub'd)
+            
+            Self::new(cards_cp).categorize_simple()  // only care about category as the key (rest is the same no matter how the J is sub'd)
         }).max().expect("Expected substitutable Jokers (should've checked for simple previously)")
     }
 
@@ -103,32 +103,23 @@ fn parse_card(c: char) -> Card {
         'Q' => 12,
         'K' => 13,
         'A' => 14,
-        num if ('2' <= num && num <= '9') => num.to_digit(10).unwrap() as u8,
-        _ => panic!("Bad card char")
+        num if ('2'..='9').contains(&num) => num.to_digit(10).unwrap() as u8,
+        _ => unreachable!("cards are checked by parse()")
     })
 }
 fn parse_hand(s: &str) -> Hand {
-    Hand::from_cards(CardList::new(s.trim().chars().map(|c| parse_card(c)).collect_vec()))
+    Hand::from_cards(CardList::new(s.trim().chars().map(parse_card).collect_vec()))
 }
 
-pub fn part2() {
-    let contents = fs::read_to_string("./src/input.txt").expect("Should've been able to read the file");
-    let lines_s = contents.lines().map(|x| x.trim()).filter(|x| x.len() > 0);
-    let lines_v = lines_s.map(|ln| {
-        if let Some((hand_s, bid_s)) = ln.split_whitespace().collect_tuple() {
-            let hand = parse_hand(hand_s);
-            let bid: u32 = bid_s.parse().expect("Expected number as bid");
-            (hand, bid)
-        } else {
-            panic!("line format should be '<cards> <number>'")
-        }
-    });
+/// In u128 so that it can't overflow (each product is less than 2^64)
+pub fn part2(hands: &[HandBid]) -> u128 {
+    let lines_v = hands.iter().map(|HandBid { hand, bid }| (parse_hand(hand), *bid));
     let lines_with_rank =  lines_v
         .sorted()
         .enumerate()
         .map(|(rank_0based, v)| (rank_0based + 1, v));
     let ranks_and_bids = lines_with_rank.map(|(rank, (_hand, bid))| (rank, bid));
-    let products = ranks_and_bids.map(|(rank, bid)| rank * bid as usize);
-    let s: usize = products.sum();
-    println!("Part 2: {}", s);
+    let products = ranks_and_bids.map(|(rank, bid)| rank as u128 * bid as u128);
+    let s: u128 = products.sum();
+    s
 }
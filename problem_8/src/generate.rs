@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+const PRIMES: &[usize] = &[43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+
+struct Names {
+    used: HashSet<String>,
+    len: usize,
+}
+impl Names {
+    /// A new name ending in `last` (or in anything but 'A' or 'Z' if `None`)
+    fn new_name(&mut self, rng: &mut Rng, last: Option<char>) -> String {
+        loop {
+            let mut name: String = (1..self.len)
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect();
+            name.push(last.unwrap_or((b'B' + rng.below(24) as u8) as char));
+            if self.used.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+/// About `size` nodes, in the same shape as the real input: every start node
+/// (`..A`) reaches its end node (`..Z`) after (number of instructions * a
+/// prime) steps and then loops back to the start's next nodes, so part 2 is
+/// the LCM of those.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n_ghosts = (1 + size / 500).min(6);
+    let mut primes = PRIMES.to_vec();
+    rng.shuffle(&mut primes);
+    primes.truncate(n_ghosts);
+    let n_instructions = (size / (2 * primes.iter().sum::<usize>())).max(1);
+    let instructions: String = (0..n_instructions)
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let n_nodes = 2 * n_instructions * primes.iter().sum::<usize>();
+    // long enough for every node to get a different name
+    let len = (3..)
+        .find(|n| 24 * 26_usize.pow(*n - 1) >= 2 * n_nodes)
+        .unwrap() as usize;
+    let mut names = Names {
+        used: HashSet::from(["AAA".to_string(), "ZZZ".to_string()]),
+        len,
+    };
+    let mut lines = Vec::new();
+    for (i, prime) in primes.iter().enumerate() {
+        let (start, end) = match i {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (
+                names.new_name(rng, Some('A')),
+                names.new_name(rng, Some('Z')),
+            ),
+        };
+        // steps 1 to n-1 have two nodes each, step n is the end node
+        let n_steps = n_instructions * prime;
+        let steps: Vec<Vec<String>> = (1..n_steps)
+            .map(|_| vec![names.new_name(rng, None), names.new_name(rng, None)])
+            .chain([vec![end.clone()]])
+            .collect();
+        let (left, right) = (rng.choose(&steps[0]), rng.choose(&steps[0]));
+        lines.push(format!("{start} = ({left}, {right})"));
+        lines.push(format!("{end} = ({left}, {right})"));
+        for pair in steps.windows(2) {
+            for node in &pair[0] {
+                let (left, right) = (rng.choose(&pair[1]), rng.choose(&pair[1]));
+                lines.push(format!("{node} = ({left}, {right})"));
+            }
+        }
+    }
+    rng.shuffle(&mut lines);
+    format!("{instructions}\n\n{}\n", lines.join("\n"))
+}
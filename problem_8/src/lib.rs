@@ -0,0 +1,172 @@
+use itertools::Itertools;
+use itertools::FoldWhile;
+use std::collections::{HashMap, HashSet};
+use num::Integer;
+use aoc_common::parse::split_once;
+use aoc_common::{find_cycle_hashed, ParseError, Rng, Solution, SolveError};
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Instruction {
+    Left,
+    Right
+}
+fn parse_instructions(ln: &str) -> Result<Vec<Instruction>, ParseError> {
+    let ln = ln.trim();
+    ln.char_indices().map(|(i, c)| {
+        match c {
+            'L' => Ok(Instruction::Left),
+            'R' => Ok(Instruction::Right),
+            _ => Err(ParseError::new(&ln[i..i + c.len_utf8()], "an instruction ('L' or 'R')"))
+        }
+    }).collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    name: String,
+    out: (String, String)
+}
+impl Node {
+    fn new_from_str(name: &str, out: (&str, &str)) -> Self {
+        Self { name: name.to_string(), out: (out.0.to_string(), out.1.to_string()) }
+    }
+    fn get_next_name(&self, instr: Instruction) -> &String {
+        match instr {
+            Instruction::Left => &self.out.0,
+            Instruction::Right => &self.out.1,
+        }
+    }
+}
+
+fn parse_node_line(ln: &str) -> Result<(&str, (&str, &str)), ParseError> {
+    let (name_s, right) = split_once(ln, "=")?;
+    let name = name_s.trim();
+    let out = parse_node_tuple(right.trim())?;
+    Ok((name, out))
+}
+fn parse_node_tuple(right: &str) -> Result<(&str, &str), ParseError> {
+    let inner = right.strip_prefix('(').and_then(|v| v.strip_suffix(')'))
+        .ok_or_else(|| ParseError::new(right, "'(<left>, <right>)'"))?;
+    let (out_l, out_r) = split_once(inner, ",")?;
+    Ok((out_l.trim(), out_r.trim()))
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    instructions: Vec<Instruction>,
+    nodes: HashMap<String, Node>,
+}
+
+pub fn parse(contents: &str) -> Result<Network, ParseError> {
+    let lines = contents.lines()
+        .collect_vec();
+    let parse_lines = || {
+        let instructions_line = lines
+            .first()
+            .ok_or_else(|| ParseError::at_end(contents, "a line of instructions"))?;
+        let instructions = parse_instructions(instructions_line)?;
+        let nodes: Vec<_> = lines[1..].iter().map(|ln| parse_node_line(ln)).try_collect()?;
+        // check that every node leads somewhere so that following them can't fail
+        let names: HashSet<_> = nodes.iter().map(|(name, _out)| *name).collect();
+        if let Some(missing) = nodes.iter().flat_map(|(_name, out)| [out.0, out.1]).find(|out| !names.contains(out)) {
+            return Err(ParseError::new(missing, "the name of a node"));
+        }
+        let nodes_kv: HashMap<_, _> = nodes.into_iter().map(|(name, out)| (name.to_string(), Node::new_from_str(name, out))).collect();
+        Ok(Network { instructions, nodes: nodes_kv })
+    };
+    parse_lines().map_err(|e: ParseError| e.locate(contents))
+}
+
+/// There are only this many (node, instruction) states so after this many
+/// steps it must be going round a cycle that never reaches the end
+fn max_steps(network: &Network) -> usize {
+    network.nodes.len() * network.instructions.len()
+}
+
+fn never_ends(start: &str) -> SolveError {
+    SolveError::new(format!("the path from {start} never reaches an end"))
+}
+
+pub fn part1(network: &Network) -> Result<usize, SolveError> {
+    let Network { instructions, nodes: nodes_kv } = network;
+    if !nodes_kv.contains_key("AAA") {
+        return Err(SolveError::new("there is no node AAA"));
+    }
+    let max_steps = max_steps(network);
+    aoc_common::trace!("{:?}", nodes_kv);
+    let (end, amount) = instructions.iter().cycle().fold_while(("AAA".to_string(), 0), |(curr_s, n), instr| {
+        aoc_common::trace!("{:?}, {:?}", curr_s, instr);
+        if curr_s == "ZZZ" || n > max_steps { return FoldWhile::Done((curr_s, n)); }
+        FoldWhile::Continue((nodes_kv[&curr_s].get_next_name(*instr).clone(), n+1))
+    }).into_inner();
+    if end != "ZZZ" {
+        return Err(never_ends("AAA"));
+    }
+    Ok(amount)
+}
+
+fn is_end(name: &str) -> bool {
+    name.ends_with('Z')
+}
+
+pub fn part2(network: &Network) -> Result<u64, SolveError> {
+    let Network { instructions, nodes: nodes_kv } = network;
+    let starting_nodes = nodes_kv.keys().filter(|k| k.ends_with('A')).collect_vec();
+    if starting_nodes.is_empty() {
+        return Err(SolveError::new("there are no nodes ending in A"));
+    }
+    aoc_common::trace!("{:?}", nodes_kv);
+    aoc_common::debug!("{:?}", starting_nodes);
+    // the (node, instruction index) states of each path, which must end up going round a cycle
+    let paths = starting_nodes.iter().map(|start| {
+        let path = find_cycle_hashed((start.as_str(), 0), |&(curr_s, i)| {
+            (nodes_kv[curr_s].get_next_name(instructions[i]).as_str(), (i + 1) % instructions.len())
+        });
+        aoc_common::debug!("{start} repeats every {} steps after the first {}", path.cycle_len, path.prefix_len);
+        path
+    }).collect_vec();
+    let amounts = starting_nodes.iter().zip(&paths).map(|(start, path)| {
+        let amount = path.states.iter().position(|(curr_s, _i)| is_end(curr_s)).ok_or_else(|| never_ends(start))?;
+        aoc_common::debug!("{start} reaches {} after {amount} steps", path.states[amount].0);
+        Ok(amount as u64)
+    });
+    // NOTE: this 'lcm of each run' method will ONLY work for the specially crafted input
+    // that AoC gives us that has extra assumptions that are UNDOCUMENTED
+    // (amounts are never 0 as the starts end in 'A', not 'Z')
+    let lcm = amounts.process_results(|mut amounts| {
+        amounts.try_fold(1_u64, |a, b| (a / a.gcd(&b)).checked_mul(b))
+    })?.ok_or_else(SolveError::overflow)?;
+    // so at least check that it is a time when all the paths are at an end
+    if let Some(start) = starting_nodes.iter().zip(&paths).find(|(_start, path)| !is_end(path.nth(lcm).0)).map(|(start, _)| start) {
+        return Err(SolveError::new(format!("the path from {start} isn't at an end after {lcm} steps (the LCM of when each path first gets to one)")));
+    }
+    Ok(lcm)
+}
+
+pub struct Day8;
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    const DIR: &'static str = CRATE_DIR;
+
+    type Model = Network;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(network: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(network)
+    }
+    fn part2(network: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(network)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
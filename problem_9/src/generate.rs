@@ -0,0 +1,21 @@
+use aoc_common::Rng;
+
+/// `size` lines of 21 values of a random polynomial of degree 0-6
+/// (so that the differences always end up as all zeros)
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let coeffs: Vec<i64> = (0..rng.range(1..8))
+            .map(|_| rng.range(0..21) as i64 - 10)
+            .collect();
+        let values: Vec<_> = (0..21)
+            .map(|x: i64| {
+                let y = coeffs.iter().rev().fold(0, |acc, c| acc * x + c);
+                y.to_string()
+            })
+            .collect();
+        out.push_str(&values.join(" "));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,80 @@
+use itertools::Itertools;
+use aoc_common::{parse_ws_list, CheckedSum, ParseError, Rng, Solution, SolveError};
+
+mod generate;
+
+/// Directory of this crate, used to find the input and example files
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn find_1st_diff(ln: &[i128]) -> Option<Vec<i128>> {
+    ln.iter().tuple_windows().map(|(a, b)| {
+        b.checked_sub(*a)
+    }).collect()
+}
+
+/// `None` if any of the differences (or the next value) overflows
+fn handle_line_vec(ln: &[i128]) -> Option<i128> {
+    if ln.iter().all(|x| *x == 0) {
+        // BASE CASE: this line is all zeroes therefore the next one is just a 0
+        return Some(0);
+    }
+
+    let diff_v = find_1st_diff(ln)?;
+    let next_diff = handle_line_vec(&diff_v)?;
+    // ...        ln.last      next
+    //    diffv.last   next_diff
+
+    // next_diff = next - ln.last
+    // => next = ln.last + next_diff
+    let next = next_diff.checked_add(*ln.last().expect("Expected non-empty line"));
+    next
+}
+
+/// The numbers are read as i128 so that the differences have plenty of room
+pub fn parse(contents: &str) -> Result<Vec<Vec<i128>>, ParseError> {
+    let lines = contents.lines();
+    lines
+        .map(|ln| parse_ws_list(ln).collect())
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(contents))
+}
+
+pub fn part1(parsed_lines: &[Vec<i128>]) -> Result<i128, SolveError> {
+    let next_values = parsed_lines.iter().map(|ln| {
+        handle_line_vec(ln)
+    }).collect::<Option<Vec<_>>>().ok_or_else(SolveError::overflow)?;
+    aoc_common::debug!("Next values: {:?}", next_values);
+    next_values.into_iter().checked_sum()
+}
+
+// same as above (hopefully) just reverse it
+pub fn part2(parsed_lines: &[Vec<i128>]) -> Result<i128, SolveError> {
+    let next_values = parsed_lines.iter().map(|ln| {
+        handle_line_vec(&ln.iter().copied().rev().collect_vec())
+    }).collect::<Option<Vec<_>>>().ok_or_else(SolveError::overflow)?;
+    aoc_common::debug!("Next values: {:?}", next_values);
+    next_values.into_iter().checked_sum()
+}
+
+pub struct Day9;
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const DIR: &'static str = CRATE_DIR;
+
+    type Model = Vec<Vec<i128>>;
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        parse(input)
+    }
+    fn part1(parsed_lines: &Self::Model) -> Result<Self::Answer1, SolveError> {
+        part1(parsed_lines)
+    }
+    fn part2(parsed_lines: &Self::Model) -> Result<Self::Answer2, SolveError> {
+        part2(parsed_lines)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
@@ -0,0 +1,93 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::parse::{parse_item, split_once};
+use aoc_common::{non_empty_lines, Answer, ParseError};
+
+use crate::days::Day;
+
+/// Name of the file in each day's `src` directory that lists the expected answers
+pub const FILE_NAME: &str = "answers.txt";
+
+/// One line of an answers file: `<input file> <part> <answer>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// File name relative to the day's `src` directory, e.g. `example_1.txt`
+    pub file: String,
+    pub part: usize,
+    pub answer: Answer,
+}
+
+pub fn path(day: &Day) -> PathBuf {
+    Path::new(day.dir).join("src").join(FILE_NAME)
+}
+
+/// Parse the contents of an answers file. Blank lines and lines starting
+/// with `#` are ignored.
+pub fn parse(contents: &str) -> Result<Vec<Expected>, ParseError> {
+    non_empty_lines(contents)
+        .filter(|ln| !ln.starts_with('#'))
+        .map(parse_line)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(contents))
+}
+
+fn parse_line(ln: &str) -> Result<Expected, ParseError> {
+    let (file, rest) = split_once(ln, " ")?;
+    let rest = rest.trim_start();
+    let (part_s, answer) = split_once(rest, " ")?;
+    let part = parse_item(part_s)?;
+    if !(1..=2).contains(&part) {
+        return Err(ParseError::new(part_s, "a part (1 or 2)"));
+    }
+    let Ok(answer) = answer.trim().parse();
+    Ok(Expected {
+        file: file.to_string(),
+        part,
+        answer,
+    })
+}
+
+/// Load the expected answers of `day` (none if it doesn't have an answers file)
+pub fn load(day: &Day) -> Result<Vec<Expected>, String> {
+    let path = path(day);
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("{}: {e}", path.display())),
+    };
+    parse(&contents).map_err(|e| format!("{}: {}", path.display(), e.render()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = parse("# comment\nexample.txt 1 8\n\nexample_2.txt  2 \t2286\n").unwrap();
+        assert_eq!(
+            answers,
+            [
+                Expected {
+                    file: "example.txt".into(),
+                    part: 1,
+                    answer: 8.into()
+                },
+                Expected {
+                    file: "example_2.txt".into(),
+                    part: 2,
+                    answer: 2286.into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_answers_line() {
+        let e = parse("example.txt 1 8\nexample.txt 3 1\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 13));
+        assert!(parse("example.txt 1\n").is_err());
+    }
+}
@@ -0,0 +1,212 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_common::parse::parse_item;
+use aoc_common::{non_empty_lines, ParseError};
+
+use crate::days::{Day, Timing};
+use crate::run::{run_part, Failure};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+impl Stats {
+    /// `None` if there are no samples
+    pub fn of(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => sorted[n / 2],
+            _ => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub day: u32,
+    pub part: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Time part `part` (1-based) of `day` on `input` `repeats` times, after one
+/// untimed warm-up run (which is also where any failure is reported).
+pub fn bench_part(
+    day: &Day,
+    part: usize,
+    input: &str,
+    repeats: usize,
+) -> Result<BenchResult, Failure> {
+    run_part(day, part, input).outcome?;
+    let mut timings: Vec<Timing> = Vec::with_capacity(repeats);
+    for _ in 0..repeats.max(1) {
+        let result = run_part(day, part, input);
+        result.outcome?;
+        timings.extend(result.timing);
+    }
+    let stats = |f: fn(&Timing) -> Duration| {
+        Stats::of(&timings.iter().map(f).collect::<Vec<_>>()).expect("at least 1 repeat")
+    };
+    Ok(BenchResult {
+        day: day.day,
+        part,
+        parse: stats(|t| t.parse),
+        solve: stats(|t| t.solve),
+    })
+}
+
+/// Where the baseline called `name` is saved
+pub fn baseline_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("bench")
+        .join(format!("{name}.txt"))
+}
+
+const HEADER: &str =
+    "# day part parse_min parse_median parse_mean solve_min solve_median solve_mean (ns)";
+
+pub fn save_baseline(name: &str, results: &[BenchResult]) -> io::Result<PathBuf> {
+    let path = baseline_path(name);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut contents = format!("{HEADER}\n");
+    for r in results {
+        let nums = [r.parse, r.solve]
+            .iter()
+            .flat_map(|s| [s.min, s.median, s.mean])
+            .map(|d| d.as_nanos().to_string())
+            .collect::<Vec<_>>();
+        contents += &format!("{} {} {}\n", r.day, r.part, nums.join(" "));
+    }
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+pub fn load_baseline(name: &str) -> io::Result<Vec<BenchResult>> {
+    let path = baseline_path(name);
+    let contents = fs::read_to_string(&path)?;
+    parse_baseline(&contents).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e.render()),
+        )
+    })
+}
+
+fn parse_baseline(contents: &str) -> Result<Vec<BenchResult>, ParseError> {
+    non_empty_lines(contents)
+        .filter(|ln| !ln.starts_with('#'))
+        .map(|ln| {
+            let words: Vec<_> = ln.split_whitespace().collect();
+            let [day, part, nums @ ..] = &words[..] else {
+                return Err(ParseError::new(ln, "'<day> <part> <6 timings>'"));
+            };
+            let nums = nums
+                .iter()
+                .map(|s| parse_item(s).map(Duration::from_nanos))
+                .collect::<Result<Vec<_>, _>>()?;
+            let [p_min, p_median, p_mean, s_min, s_median, s_mean] = nums[..] else {
+                return Err(ParseError::new(ln, "6 timings"));
+            };
+            Ok(BenchResult {
+                day: parse_item(day)?,
+                part: parse_item(part)?,
+                parse: Stats {
+                    min: p_min,
+                    median: p_median,
+                    mean: p_mean,
+                },
+                solve: Stats {
+                    min: s_min,
+                    median: s_median,
+                    mean: s_mean,
+                },
+            })
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(contents))
+}
+
+/// Short human-readable duration, e.g. `12.3ms`
+pub fn fmt_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+    match ns {
+        0..=999 => format!("{ns}ns"),
+        1_000..=999_999 => format!("{:.1}µs", ns as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", ns as f64 / 1e6),
+        _ => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+/// Relative change from `baseline` to `current`, e.g. `+12.5%`
+pub fn fmt_change(current: Duration, baseline: Duration) -> String {
+    if baseline.is_zero() {
+        return "n/a".to_string();
+    }
+    let change = current.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+    format!("{:+.1}%", change * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats() {
+        let s = Stats::of(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!((s.min, s.median, s.mean), (ms(1), ms(3), ms(3)));
+        let s = Stats::of(&[ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!((s.min, s.median, s.mean), (ms(1), ms(3), ms(4)));
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let s = Stats::of(&[ms(1), ms(2)]).unwrap();
+        let result = BenchResult {
+            day: 12,
+            part: 2,
+            parse: s,
+            solve: s,
+        };
+        let name = format!("test_round_trip_{}", std::process::id());
+        let path = save_baseline(&name, std::slice::from_ref(&result)).unwrap();
+        let loaded = load_baseline(&name);
+        fs::remove_file(path).unwrap();
+        assert_eq!(loaded.unwrap(), [result]);
+    }
+
+    #[test]
+    fn bad_baseline() {
+        let e = parse_baseline("# header\n1 1 1 2 3 4 5\n").unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (2, "6 timings"));
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(fmt_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(fmt_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(fmt_duration(Duration::from_secs(2)), "2.00s");
+        assert_eq!(fmt_change(ms(15), ms(10)), "+50.0%");
+        assert_eq!(fmt_change(ms(9), ms(10)), "-10.0%");
+    }
+}
@@ -0,0 +1,555 @@
+use std::fmt::Display;
+use std::thread;
+
+use aoc_common::input::{self, InputSource};
+use aoc_common::trace;
+
+use crate::config::Config;
+use crate::days::{self, Day};
+use crate::report::Format;
+use crate::scaffold;
+
+pub fn usage() -> String {
+    format!(
+        "\
+usage: aoc run [--day N]... [--days FIRST-LAST] [--all] [--part P] [INPUT OPTIONS]
+               [--format text|json] [--parallel | --jobs N] [--watch] [-v | -vv]
+       aoc verify [--day N]... [--days FIRST-LAST] [--all] [--part P] [--smoke] [-v | -vv]
+       aoc bench [--day N]... [--days FIRST-LAST] [--all] [--part P] [INPUT OPTIONS]
+                 [--repeats N] [--save NAME] [--baseline NAME]
+       aoc gen --day N [--seed S] [--size N]
+       aoc new [--day N]
+
+  run                      print the answers
+  verify                   check the answers for src/input.txt against the
+                           ones recorded in src/answers.txt (and aoc.toml)
+  bench                    time the parsing and solving of each part (use a
+                           release build for meaningful numbers)
+  gen                      print a random input for day N
+  new                      create and register the crate for a new day
+                           (default: the day after the last one solved)
+
+  --day N                  run day N (can be given more than once)
+  --days FIRST-LAST        run every day from FIRST to LAST (inclusive)
+  --all                    run every day (the default if no days are given)
+  --part P                 only run part P (1 or 2) of each day
+  --format text|json       (run) print the answers as text (the default) or as
+                           one JSON object per line
+  --parallel               (run) run the parts on one thread per CPU and print
+                           a summary table at the end
+  --jobs N, -j N           (run) like --parallel, but with N threads
+  --watch, -w              (run) run each day again whenever its code or input
+                           changes and show how the answers and times changed
+  --smoke                  (verify) check each day's smoke test example
+                           (set in aoc.toml) instead of its input
+  --seed S                 (gen) seed for the random input (default: random)
+  --size N                 (gen) how big the input should be, e.g. the number
+                           of lines (the exact meaning depends on the day,
+                           default: 100)
+{}
+  --repeats N              (bench) time each part N times (default: 10)
+  --save NAME              (bench) save the timings as the baseline NAME
+  --baseline NAME          (bench) compare the timings to the baseline NAME
+
+input options (default: each day's src/input.txt):
+{}
+
+The defaults for most of these are set in {}.",
+        trace::USAGE,
+        input::USAGE,
+        crate::config::PATH
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(RunArgs),
+    Bench(BenchArgs),
+    Gen(GenArgs),
+    New(NewArgs),
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RunArgs {
+    /// empty means all of the days
+    pub days: Vec<u32>,
+    pub part: Option<usize>,
+    pub input: InputSource,
+    pub format: Format,
+    /// Number of threads to run the parts on, `None` to run them one at a
+    /// time (printing each answer straight away)
+    pub jobs: Option<usize>,
+    /// How much the solutions print about what they're doing, see [`aoc_common::trace`]
+    pub verbosity: u8,
+    /// Keep running the days again when their files change, see [`crate::watch`]
+    pub watch: bool,
+    /// Verify the days on their smoke test examples instead of their inputs
+    pub smoke: bool,
+    pub config: Config,
+}
+impl RunArgs {
+    /// Where `day` should read its input from, see [`Config::input`]
+    pub fn input_for(&self, day: &Day) -> InputSource {
+        self.config.input(day, &self.input)
+    }
+
+    pub fn selected_days(&self) -> Vec<&'static Day> {
+        if self.days.is_empty() {
+            return days::DAYS.iter().collect();
+        }
+        self.days.iter().filter_map(|d| days::get_day(*d)).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchArgs {
+    pub run: RunArgs,
+    pub repeats: usize,
+    pub save: Option<String>,
+    pub baseline: Option<String>,
+}
+impl Default for BenchArgs {
+    fn default() -> Self {
+        Self {
+            run: RunArgs::default(),
+            repeats: 10,
+            save: None,
+            baseline: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenArgs {
+    pub day: u32,
+    /// `None` to pick one at random
+    pub seed: Option<u64>,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewArgs {
+    pub day: u32,
+}
+
+fn err(msg: impl Display) -> String {
+    msg.to_string()
+}
+
+fn value_of(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("{flag} requires a value"))
+}
+
+fn parse_num<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value_of(flag, value)?;
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid value for {flag}: {value:?}"))
+}
+
+/// Whether the command in `args` uses `aoc.toml`: `help`, `gen` and `new`
+/// don't, so they work even if it is broken
+pub fn uses_config(args: &[String]) -> bool {
+    matches!(
+        args.first().map(String::as_str),
+        Some("run" | "verify" | "bench")
+    ) && !args.iter().any(|a| a == "-h" || a == "--help")
+}
+
+/// Parse the command line, with the defaults from `config`
+pub fn parse_args(
+    args: impl IntoIterator<Item = String>,
+    config: &Config,
+) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = args.next();
+    match command.as_deref() {
+        Some("run" | "verify" | "bench") => {}
+        Some("gen") => return parse_gen_args(args),
+        Some("new") => return parse_new_args(args),
+        Some("-h" | "--help" | "help") => return Ok(Command::Help),
+        Some(other) => return Err(format!("unknown command: {other:?}")),
+        None => return Err(err("expected a command")),
+    }
+    let is_bench = command.as_deref() == Some("bench");
+    let mut bench_args = BenchArgs::default();
+    let run_args = &mut bench_args.run;
+    run_args.config = config.clone();
+    if !is_bench {
+        run_args.verbosity = config.verbosity;
+    }
+    let mut format = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => run_args.days.push(parse_num(&arg, args.next())?),
+            "--days" => {
+                let value = args.next().ok_or_else(|| err("--days requires a value"))?;
+                let (first, last) = value
+                    .split_once('-')
+                    .ok_or_else(|| format!("--days should be FIRST-LAST, got {value:?}"))?;
+                let first: u32 = parse_num("--days", Some(first.to_string()))?;
+                let last: u32 = parse_num("--days", Some(last.to_string()))?;
+                if first > last {
+                    return Err(format!(
+                        "--days should be FIRST-LAST with FIRST <= LAST, got {value:?}"
+                    ));
+                }
+                run_args.days.extend(first..=last);
+            }
+            "--all" | "-a" => run_args.days.clear(),
+            "--part" | "-p" => {
+                let part = parse_num(&arg, args.next())?;
+                if !(1..=2).contains(&part) {
+                    return Err(format!("--part should be 1 or 2, got {part}"));
+                }
+                run_args.part = Some(part);
+            }
+            "--format" | "-f" => {
+                format = Some(match value_of(&arg, args.next())?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => return Err(format!("--format should be text or json, got {other:?}")),
+                });
+            }
+            "--parallel" => {
+                run_args.jobs = Some(thread::available_parallelism().map_or(4, |n| n.get()))
+            }
+            "--jobs" | "-j" => match parse_num(&arg, args.next())? {
+                0 => return Err(err("--jobs should be at least 1")),
+                n => run_args.jobs = Some(n),
+            },
+            "--watch" | "-w" => run_args.watch = true,
+            "--smoke" => run_args.smoke = true,
+            "--repeats" | "-r" | "--save" | "--baseline" if !is_bench => {
+                return Err(format!("{arg} can only be used with bench"))
+            }
+            "--repeats" | "-r" => bench_args.repeats = parse_num(&arg, args.next())?,
+            "--save" => bench_args.save = Some(value_of(&arg, args.next())?),
+            "--baseline" => bench_args.baseline = Some(value_of(&arg, args.next())?),
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if trace::parse_verbosity_arg(&arg).is_some() => {
+                if is_bench {
+                    return Err(err(
+                        "-v can't be used with bench (printing would skew the timings)",
+                    ));
+                }
+                let more = trace::parse_verbosity_arg(&arg).unwrap_or_default();
+                run_args.verbosity = run_args.verbosity.saturating_add(more);
+            }
+            _ => {
+                run_args.input = InputSource::parse_arg(&arg, || args.next())?
+                    .ok_or_else(|| format!("unknown argument: {arg:?}"))?;
+            }
+        }
+    }
+    if matches!(run_args.input, InputSource::File(_) | InputSource::Stdin)
+        && run_args.selected_days().len() != 1
+    {
+        return Err(err("--input can only be used when running a single day"));
+    }
+    for d in &run_args.days {
+        if days::get_day(*d).is_none() {
+            return Err(format!("day {d} has not been solved yet"));
+        }
+    }
+    run_args.format = match format {
+        Some(format) => format,
+        // watch mode prints its own text
+        None if command.as_deref() == Some("run") && !run_args.watch => config.format,
+        None => Format::Text,
+    };
+    if command.as_deref() != Some("run") {
+        if run_args.format != Format::Text {
+            return Err(err("--format can only be used with run"));
+        }
+        if run_args.jobs.is_some() {
+            return Err(err("--parallel and --jobs can only be used with run"));
+        }
+        if run_args.watch {
+            return Err(err("--watch can only be used with run"));
+        }
+    }
+    if run_args.watch {
+        if run_args.jobs.is_some() || run_args.format != Format::Text {
+            return Err(err(
+                "--watch can't be used with --parallel, --jobs or --format",
+            ));
+        }
+        if run_args.input == InputSource::Stdin {
+            return Err(err("--watch needs an input file to watch, not stdin"));
+        }
+    }
+    if run_args.smoke && command.as_deref() != Some("verify") {
+        return Err(err("--smoke can only be used with verify"));
+    }
+    match command.as_deref() {
+        Some("verify") if run_args.input != InputSource::Default => Err(err(
+            "verify always uses the input (or the smoke test example with --smoke)",
+        )),
+        Some("verify") => Ok(Command::Verify(bench_args.run)),
+        Some("bench") => Ok(Command::Bench(bench_args)),
+        _ => Ok(Command::Run(bench_args.run)),
+    }
+}
+
+fn parse_gen_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let (mut day, mut seed, mut size) = (None, None, 100);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_num(&arg, args.next())?),
+            "--seed" => seed = Some(parse_num(&arg, args.next())?),
+            "--size" => size = parse_num(&arg, args.next())?,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument for gen: {arg:?}")),
+        }
+    }
+    let day = day.ok_or_else(|| err("gen requires --day"))?;
+    if days::get_day(day).is_none() {
+        return Err(format!("day {day} has not been solved yet"));
+    }
+    Ok(Command::Gen(GenArgs { day, seed, size }))
+}
+
+fn parse_new_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = scaffold::next_day();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = parse_num(&arg, args.next())?,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument for new: {arg:?}")),
+        }
+    }
+    if !(1..=25).contains(&day) {
+        return Err(format!("--day should be from 1 to 25, got {day}"));
+    }
+    if days::get_day(day).is_some() {
+        return Err(format!("day {day} has already been solved"));
+    }
+    Ok(Command::New(NewArgs { day }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Command, String> {
+        parse_args(s.split_whitespace().map(String::from), &Config::default())
+    }
+
+    #[test]
+    fn run_single_day_and_part() {
+        assert_eq!(
+            parse("run --day 10 --part 2"),
+            Ok(Command::Run(RunArgs {
+                days: vec![10],
+                part: Some(2),
+                input: InputSource::Default,
+                format: Format::Text,
+                jobs: None,
+                verbosity: 0,
+                watch: false,
+                smoke: false,
+                config: Config::default(),
+            }))
+        );
+    }
+
+    #[test]
+    fn run_range_of_days() {
+        let Ok(Command::Run(args)) = parse("run --days 3-5 --day 9") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.days, [3, 4, 5, 9]);
+        assert_eq!(args.part, None);
+        let Ok(Command::Run(args)) = parse("run --days 4-4") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.days, [4]);
+        assert!(parse("run --days 5-3").is_err());
+    }
+
+    #[test]
+    fn run_all_by_default() {
+        let Ok(Command::Run(args)) = parse("run") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.selected_days().len(), days::DAYS.len());
+    }
+
+    #[test]
+    fn run_with_input() {
+        let Ok(Command::Run(args)) = parse("run --day 3 --example orig") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.input, InputSource::Example("orig".into()));
+        let Ok(Command::Run(args)) = parse("run -d 3 -") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.input, InputSource::Stdin);
+        assert!(parse("run --days 3-4 --input x.txt").is_err());
+    }
+
+    #[test]
+    fn run_json() {
+        let Ok(Command::Run(args)) = parse("run --format json -p 1") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.format, Format::Json);
+        assert!(parse("run --format yaml").is_err());
+        assert!(parse("verify --format json").is_err());
+        assert!(parse("bench -f json").is_err());
+    }
+
+    #[test]
+    fn run_parallel() {
+        let Ok(Command::Run(args)) = parse("run --jobs 3") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.jobs, Some(3));
+        let Ok(Command::Run(args)) = parse("run --parallel") else {
+            panic!("expected run command")
+        };
+        assert!(args.jobs.is_some_and(|n| n >= 1));
+        assert!(parse("run -j 0").is_err());
+        assert!(parse("verify --parallel").is_err());
+    }
+
+    #[test]
+    fn run_verbose() {
+        let Ok(Command::Run(args)) = parse("run -d 8 -v") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.verbosity, 1);
+        let Ok(Command::Verify(args)) = parse("verify -vv -v") else {
+            panic!("expected verify command")
+        };
+        assert_eq!(args.verbosity, 3);
+        assert!(parse("bench -v").is_err());
+        assert!(parse("gen -d 8 -v").is_err());
+    }
+
+    #[test]
+    fn run_watch() {
+        let Ok(Command::Run(args)) = parse("run -d 5 --watch -e 2") else {
+            panic!("expected run command")
+        };
+        assert!(args.watch);
+        assert!(parse("run -d 5 -w -").is_err());
+        assert!(parse("run -w --parallel").is_err());
+        assert!(parse("run -w -f json").is_err());
+        assert!(parse("verify --watch").is_err());
+        assert!(parse("bench --watch").is_err());
+    }
+
+    #[test]
+    fn verify() {
+        let Ok(Command::Verify(args)) = parse("verify --days 1-2 -p 1") else {
+            panic!("expected verify command")
+        };
+        assert_eq!(args.days, [1, 2]);
+        assert_eq!(args.part, Some(1));
+        assert!(parse("verify --example orig").is_err());
+        let Ok(Command::Verify(args)) = parse("verify --smoke") else {
+            panic!("expected verify command")
+        };
+        assert!(args.smoke);
+        assert!(parse("run --smoke").is_err());
+    }
+
+    #[test]
+    fn defaults_from_config() {
+        let config = Config::parse("format = \"json\"\nverbosity = 1").unwrap();
+        let parse = |s: &str| parse_args(s.split_whitespace().map(String::from), &config);
+        let Ok(Command::Run(args)) = parse("run -d 3 -v") else {
+            panic!("expected run command")
+        };
+        assert_eq!((args.format, args.verbosity), (Format::Json, 2));
+        assert_eq!(args.config, config);
+        let Ok(Command::Run(args)) = parse("run -f text") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.format, Format::Text);
+        let Ok(Command::Run(args)) = parse("run -w") else {
+            panic!("expected run command")
+        };
+        assert_eq!(args.format, Format::Text);
+        let Ok(Command::Verify(args)) = parse("verify") else {
+            panic!("expected verify command")
+        };
+        assert_eq!((args.format, args.verbosity), (Format::Text, 1));
+        let Ok(Command::Bench(args)) = parse("bench") else {
+            panic!("expected bench command")
+        };
+        assert_eq!(args.run.verbosity, 0);
+    }
+
+    #[test]
+    fn bench() {
+        let Ok(Command::Bench(args)) = parse("bench -d 7 --repeats 3 --baseline old") else {
+            panic!("expected bench command")
+        };
+        assert_eq!(args.run.days, [7]);
+        assert_eq!(args.repeats, 3);
+        assert_eq!(args.save, None);
+        assert_eq!(args.baseline.as_deref(), Some("old"));
+        let Ok(Command::Bench(args)) = parse("bench --save new") else {
+            panic!("expected bench command")
+        };
+        assert_eq!(args.repeats, 10);
+        assert_eq!(args.save.as_deref(), Some("new"));
+        assert!(parse("run --repeats 3").is_err());
+        assert!(parse("bench --save").is_err());
+    }
+
+    #[test]
+    fn gen() {
+        assert_eq!(
+            parse("gen --day 5 --seed 42 --size 1000"),
+            Ok(Command::Gen(GenArgs {
+                day: 5,
+                seed: Some(42),
+                size: 1000
+            }))
+        );
+        let Ok(Command::Gen(args)) = parse("gen -d 12") else {
+            panic!("expected gen command")
+        };
+        assert_eq!((args.seed, args.size), (None, 100));
+        assert!(parse("gen").is_err());
+        assert!(parse("gen -d 12 --part 1").is_err());
+        assert!(parse("run --seed 3").is_err());
+    }
+
+    #[test]
+    fn new() {
+        let last = days::DAYS.last().unwrap().day;
+        assert_eq!(parse("new"), Ok(Command::New(NewArgs { day: last + 1 })));
+        assert_eq!(parse("new --day 20"), Ok(Command::New(NewArgs { day: 20 })));
+        assert!(parse("new --day 1").is_err());
+        assert!(parse("new --day 26").is_err());
+        assert!(parse("new --part 1").is_err());
+    }
+
+    #[test]
+    fn only_run_verify_and_bench_use_the_config() {
+        let uses =
+            |s: &str| uses_config(&s.split_whitespace().map(String::from).collect::<Vec<_>>());
+        assert!(uses("run -d 3") && uses("verify") && uses("bench --day 4"));
+        assert!(!uses("help") && !uses("run --help") && !uses("gen --day 3") && !uses("new"));
+        assert!(!uses(""));
+    }
+
+    #[test]
+    fn bad_args_are_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("walk").is_err());
+        assert!(parse("run --part 3").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --day x").is_err());
+        assert!(parse("run --days 5").is_err());
+        assert!(parse("run --day 99").is_err());
+    }
+}
@@ -0,0 +1,221 @@
+//! `aoc.toml`: the project's defaults for the runner (where the inputs are,
+//! which example to smoke test each day on, how fast each part should be, ...)
+//! so that they are written down instead of being conventions to remember.
+//! Options given on the command line override them.
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_common::input::{example_path, list_examples, InputSource};
+use aoc_common::Answer;
+use serde::Deserialize;
+
+use crate::answers::Expected;
+use crate::days::Day;
+use crate::report::Format;
+
+/// The config file, in the root of the workspace
+pub const PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/aoc.toml");
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Directory (relative to each day's crate) that the default `input.txt`
+    /// is read from, with `{day}` replaced by the day number. `None` for `src`.
+    pub input_dir: Option<String>,
+    pub format: Format,
+    pub verbosity: u8,
+    /// How long each part may take (parsing and solving), in milliseconds
+    pub budget_ms: Option<u64>,
+    /// Settings for single days, keyed by the day number
+    pub days: BTreeMap<String, DayConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DayConfig {
+    /// The example that `aoc verify --smoke` runs, see [`Config::smoke_example`]
+    pub example: Option<String>,
+    /// Overrides [`Config::budget_ms`] for this day
+    pub budget_ms: Option<u64>,
+    /// Expected answer for the day's input
+    pub part1: Option<AnswerValue>,
+    pub part2: Option<AnswerValue>,
+}
+
+/// An answer written as a TOML number or string. TOML integers have to fit
+/// in an `i64`, so bigger answers are written as strings (which are still
+/// compared as numbers, see [`Answer`]).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum AnswerValue {
+    Number(i64),
+    Text(String),
+}
+
+impl AnswerValue {
+    pub fn to_answer(&self) -> Answer {
+        match self {
+            AnswerValue::Number(n) => Answer::from(*n),
+            AnswerValue::Text(s) => {
+                let Ok(answer) = s.parse();
+                answer
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Read [`PATH`] (the defaults if there isn't one)
+    pub fn load() -> Result<Self, String> {
+        match fs::read_to_string(PATH) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| format!("{PATH}: {e}")),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{PATH}: {e}")),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(contents).map_err(|e| e.to_string())?;
+        for key in config.days.keys() {
+            if !key.parse().is_ok_and(|day: u32| (1..=25).contains(&day)) {
+                return Err(format!("[days.{key}] should be a day from 1 to 25"));
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn day(&self, day: u32) -> Option<&DayConfig> {
+        self.days.get(&day.to_string())
+    }
+
+    /// Where `day` should read its input from when the command line asked for `input`
+    pub fn input(&self, day: &Day, input: &InputSource) -> InputSource {
+        match (input, &self.input_dir) {
+            (InputSource::Default, Some(dir)) => {
+                let dir = dir.replace("{day}", &day.day.to_string());
+                InputSource::File(Path::new(day.dir).join(dir).join("input.txt"))
+            }
+            _ => input.clone(),
+        }
+    }
+
+    /// How long each part of `day` may take, if it has a budget
+    pub fn budget(&self, day: u32) -> Option<Duration> {
+        self.day(day)
+            .and_then(|d| d.budget_ms)
+            .or(self.budget_ms)
+            .map(Duration::from_millis)
+    }
+
+    /// The expected answers for the input of `day`, like the `input.txt`
+    /// lines of its `answers.txt`
+    pub fn answers(&self, day: u32) -> Vec<Expected> {
+        let Some(d) = self.day(day) else {
+            return Vec::new();
+        };
+        [&d.part1, &d.part2]
+            .into_iter()
+            .zip(1..)
+            .filter_map(|(answer, part)| {
+                Some(Expected {
+                    file: "input.txt".to_string(),
+                    part,
+                    answer: answer.as_ref()?.to_answer(),
+                })
+            })
+            .collect()
+    }
+
+    /// The example file to smoke test `day` on: the configured one, or else
+    /// `example.txt`, or else the first of its examples
+    pub fn smoke_example(&self, day: &Day) -> Option<PathBuf> {
+        let dir = Path::new(day.dir);
+        match self.day(day.day).and_then(|d| d.example.as_deref()) {
+            Some(name) => example_path(dir, name),
+            None => example_path(dir, "example")
+                .or_else(|| example_path(dir, list_examples(dir).first()?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn parse_config() {
+        let config = Config::parse(
+            r#"
+            input_dir = "../inputs/{day}"
+            format = "json"
+            budget_ms = 1000
+
+            [days.3]
+            example = "orig"
+            budget_ms = 50
+            part1 = 527144
+            part2 = "81463996"
+
+            [days.4]
+            part1 = "340282366920938463463374607431768211455"
+            part2 = "ABC"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(config.verbosity, 0);
+        assert_eq!(config.budget(3), Some(Duration::from_millis(50)));
+        assert_eq!(config.budget(4), Some(Duration::from_secs(1)));
+        let answers: Vec<_> = config
+            .answers(3)
+            .into_iter()
+            .map(|e| e.answer.to_string())
+            .collect();
+        assert_eq!(answers, ["527144", "81463996"]);
+        let answers: Vec<_> = config.answers(4).into_iter().map(|e| e.answer).collect();
+        assert_eq!(answers, [Answer::from(u128::MAX), Answer::from("ABC")]);
+        assert!(config.answers(5).is_empty());
+
+        let day = days::get_day(3).unwrap();
+        assert_eq!(
+            config.input(day, &InputSource::Default),
+            InputSource::File(Path::new(day.dir).join("../inputs/3/input.txt"))
+        );
+        let example = InputSource::Example("orig".into());
+        assert_eq!(config.input(day, &example), example);
+        let smoke = config.smoke_example(day).unwrap();
+        assert_eq!(smoke.file_name().unwrap(), "example_orig.txt");
+    }
+
+    #[test]
+    fn defaults() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config, Config::default());
+        let day = days::get_day(8).unwrap();
+        assert_eq!(
+            config.input(day, &InputSource::Default),
+            InputSource::Default
+        );
+        assert_eq!(config.budget(8), None);
+        let smoke = config.smoke_example(day).unwrap();
+        assert_eq!(smoke.file_name().unwrap(), "example_1.txt");
+    }
+
+    #[test]
+    fn bad_configs() {
+        assert!(Config::parse("format = \"yaml\"").is_err());
+        assert!(Config::parse("budget = 5").is_err());
+        assert!(Config::parse("[days.26]\nbudget_ms = 5").is_err());
+        assert!(Config::parse("[days.x]").is_err());
+        assert!(Config::parse("[days.4]\nexampel = \"a\"").is_err());
+    }
+
+    #[test]
+    fn workspace_config_is_valid() {
+        Config::load().unwrap();
+    }
+}
@@ -0,0 +1,96 @@
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use aoc_common::solution::parse_input;
+use aoc_common::{Answer, Rng, Solution};
+
+use crate::run::Failure;
+
+/// Parses the input text and runs one part of a day on it, returning its
+/// answer and how long each step took
+pub type PartFn = fn(&str) -> Result<(Answer, Timing), Failure>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u32,
+    /// Directory of the day's crate (where its `src/input.txt` is)
+    pub dir: &'static str,
+    /// `parts[0]` is part 1, `parts[1]` is part 2 (if implemented)
+    pub parts: &'static [PartFn],
+    /// Random input generator, see [`Solution::generate`]
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Day {
+    /// Entry for the day solved by `S`
+    pub const fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            dir: S::DIR,
+            parts: Parts::<S>::ALL.split_at(S::PARTS).0,
+            generate: S::generate,
+        }
+    }
+}
+
+struct Parts<S>(PhantomData<S>);
+impl<S: Solution> Parts<S> {
+    const ALL: &'static [PartFn] = &[run_part::<S, 1>, run_part::<S, 2>];
+}
+
+fn run_part<S: Solution, const PART: usize>(input: &str) -> Result<(Answer, Timing), Failure> {
+    let start = Instant::now();
+    let model = parse_input::<S>(input).map_err(Failure::Parse)?;
+    let parsed = Instant::now();
+    let (answer, solved): (Answer, _) = if PART == 1 {
+        let answer = S::part1(&model).map_err(Failure::Solve)?;
+        (answer.into(), Instant::now())
+    } else {
+        let answer = S::part2(&model).map_err(Failure::Solve)?;
+        (answer.into(), Instant::now())
+    };
+    let timing = Timing {
+        parse: parsed - start,
+        solve: solved - parsed,
+    };
+    Ok((answer, timing))
+}
+
+/// Every solved day. A new day only needs to implement [`Solution`] and be added here.
+pub const DAYS: &[Day] = &[
+    Day::of::<problem_1::Day1>(),
+    Day::of::<problem_2::Day2>(),
+    Day::of::<problem_3::Day3>(),
+    Day::of::<problem_4::Day4>(),
+    Day::of::<problem_5::Day5>(),
+    Day::of::<problem_6::Day6>(),
+    Day::of::<problem_7::Day7>(),
+    Day::of::<problem_8::Day8>(),
+    Day::of::<problem_9::Day9>(),
+    Day::of::<problem_10::Day10>(),
+    Day::of::<problem_11::Day11>(),
+    Day::of::<problem_12::Day12>(),
+    Day::of::<problem_13::Day13>(),
+    Day::of::<problem_14::Day14>(),
+];
+
+pub fn get_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_in_order_and_unique() {
+        assert!(DAYS.windows(2).all(|w| w[0].day < w[1].day));
+        assert!(DAYS.iter().all(|d| (1..=2).contains(&d.parts.len())));
+    }
+}
@@ -0,0 +1,11 @@
+//! The `aoc` runner: runs the solutions of any of the days from one place
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod days;
+pub mod report;
+pub mod run;
+pub mod scaffold;
+pub mod verify;
+pub mod watch;
@@ -1,3 +1,455 @@
-fn main() {
-    println!("Hello, world!");
+use std::env;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aoc::answers;
+use aoc::bench::{self, fmt_change, fmt_duration, BenchResult, Stats};
+use aoc::cli::{self, BenchArgs, Command, GenArgs, NewArgs, RunArgs};
+use aoc::config::Config;
+use aoc::days;
+use aoc::report::{Format, Record};
+use aoc::run::{run_parallel, run_part, Failure, Job, PartResult};
+use aoc::scaffold;
+use aoc::verify::{self, Verdict};
+use aoc::watch;
+use aoc_common::input::InputSource;
+use aoc_common::{trace, Rng};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let config = if cli::uses_config(&args) {
+        match Config::load() {
+            Ok(config) => config,
+            Err(msg) => {
+                eprintln!("error: {msg}");
+                return ExitCode::from(2);
+            }
+        }
+    } else {
+        Config::default()
+    };
+    match cli::parse_args(args, &config) {
+        Ok(Command::Help) => {
+            println!("{}", cli::usage());
+            ExitCode::SUCCESS
+        }
+        Ok(Command::Run(args)) => run(&args),
+        Ok(Command::Verify(args)) => verify(&args),
+        Ok(Command::Bench(args)) => bench(&args),
+        Ok(Command::Gen(args)) => gen(&args),
+        Ok(Command::New(args)) => new_day(&args),
+        Err(msg) => {
+            eprintln!("error: {msg}\n\n{}", cli::usage());
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    if args.watch {
+        return watch::watch(args);
+    }
+    trace::set_verbosity(args.verbosity);
+    if let Some(threads) = args.jobs {
+        return run_in_parallel(args, threads);
+    }
+    let json = args.format == Format::Json;
+    let mut n_failed = 0;
+    for day in args.selected_days() {
+        let parts = (1..=day.parts.len()).filter(|p| args.part.is_none_or(|part| part == *p));
+        let source = args.input_for(day);
+        let path = source.path(Path::new(day.dir)).ok().flatten();
+        let input = match source.read(Path::new(day.dir)) {
+            Ok(input) => input,
+            Err(e) => {
+                if json {
+                    for part in parts {
+                        n_failed += 1;
+                        let record =
+                            Record::input_error(day.day, part, path.as_deref(), e.to_string());
+                        println!("{}", record.to_json());
+                    }
+                } else {
+                    n_failed += parts.count();
+                    println!("Day {}: FAILED ({})", day.day, e);
+                }
+                continue;
+            }
+        };
+        for part in parts {
+            let result = run_part(day, part, &input);
+            if result.outcome.is_err() {
+                n_failed += 1;
+            }
+            if json {
+                println!("{}", Record::new(&result, path.as_deref()).to_json());
+                continue;
+            }
+            let budget_note = over_budget(args, &result).unwrap_or_default();
+            match result.outcome {
+                Ok(answer) => println!(
+                    "Day {} part {}: {}{}",
+                    result.day, result.part, answer, budget_note
+                ),
+                Err(Failure::Panic(msg)) => {
+                    println!("Day {} part {}: FAILED ({})", result.day, result.part, msg);
+                }
+                Err(Failure::Solve(e)) => {
+                    println!("Day {} part {}: FAILED ({})", result.day, result.part, e);
+                }
+                Err(Failure::Parse(e)) => {
+                    println!(
+                        "Day {} part {}: FAILED (bad input)",
+                        result.day, result.part
+                    );
+                    println!("{}", e.render());
+                }
+            }
+        }
+    }
+    if n_failed > 0 {
+        eprintln!("{n_failed} part(s) failed");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Run every selected part on `threads` threads, then print a table of the
+/// results (or the JSON records) in day order
+fn run_in_parallel(args: &RunArgs, threads: usize) -> ExitCode {
+    let start = Instant::now();
+    let days: Vec<_> = args
+        .selected_days()
+        .into_iter()
+        .map(|day| {
+            let source = args.input_for(day);
+            let path = source.path(Path::new(day.dir)).ok().flatten();
+            let input = source.read(Path::new(day.dir));
+            let parts: Vec<_> = (1..=day.parts.len())
+                .filter(|p| args.part.is_none_or(|part| part == *p))
+                .collect();
+            (day, path, input, parts)
+        })
+        .collect();
+    let jobs: Vec<_> = days
+        .iter()
+        .filter_map(|(day, _, input, parts)| Some((day, input.as_ref().ok()?, parts)))
+        .flat_map(|(day, input, parts)| parts.iter().map(|&part| Job { day, part, input }))
+        .collect();
+    let mut results = run_parallel(&jobs, threads).into_iter();
+    let elapsed = start.elapsed();
+
+    let json = args.format == Format::Json;
+    if !json {
+        println!(
+            "{:<6}{:<6}{:<8}{:<10}Answer",
+            "Day", "Part", "Status", "Time"
+        );
+    }
+    let (mut n_ok, mut n_failed) = (0, 0);
+    for (day, path, input, parts) in &days {
+        for &part in parts {
+            let result = match input {
+                Ok(_) => results.next().expect("Every job should have a result"),
+                Err(e) => {
+                    n_failed += 1;
+                    if json {
+                        let record =
+                            Record::input_error(day.day, part, path.as_deref(), e.to_string());
+                        println!("{}", record.to_json());
+                    } else {
+                        println!("{:<6}{:<6}{:<8}{:<10}{e}", day.day, part, "FAILED", "-");
+                    }
+                    continue;
+                }
+            };
+            match result.outcome {
+                Ok(_) => n_ok += 1,
+                Err(_) => n_failed += 1,
+            }
+            if json {
+                println!("{}", Record::new(&result, path.as_deref()).to_json());
+                continue;
+            }
+            let time = result
+                .timing
+                .map_or("-".to_string(), |t| fmt_duration(t.parse + t.solve));
+            let (status, answer) = match result.outcome {
+                Ok(answer) => ("ok", answer.to_string()),
+                Err(Failure::Panic(msg)) => ("FAILED", format!("panicked: {msg}")),
+                Err(Failure::Parse(e)) => ("FAILED", format!("bad input: {e}")),
+                Err(Failure::Solve(e)) => ("FAILED", format!("no answer: {e}")),
+            };
+            println!("{:<6}{:<6}{status:<8}{time:<10}{answer}", day.day, part);
+        }
+    }
+    if !json {
+        println!(
+            "{n_ok} ok, {n_failed} failed in {} on {threads} thread(s)",
+            fmt_duration(elapsed)
+        );
+    }
+    if n_failed > 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn verify(args: &RunArgs) -> ExitCode {
+    trace::set_verbosity(args.verbosity);
+    let (mut n_ok, mut n_wrong, mut n_missing, mut n_failed) = (0, 0, 0, 0);
+    for day in args.selected_days() {
+        let loaded = answers::load(day).and_then(|expected| {
+            let (file, source) = if args.smoke {
+                let path = args
+                    .config
+                    .smoke_example(day)
+                    .ok_or_else(|| "no example to smoke test on".to_string())?;
+                let file = path.file_name().unwrap_or_default().to_string_lossy();
+                (file.into_owned(), InputSource::File(path))
+            } else {
+                ("input.txt".to_string(), args.input_for(day))
+            };
+            let input = source.read(Path::new(day.dir)).map_err(|e| e.to_string())?;
+            // the answers in aoc.toml go first so that they take precedence
+            Ok((
+                [args.config.answers(day.day), expected].concat(),
+                file,
+                input,
+            ))
+        });
+        let (expected, file, input) = match loaded {
+            Ok(v) => v,
+            Err(e) => {
+                n_failed += 1;
+                println!("Day {}: FAILED ({})", day.day, e);
+                continue;
+            }
+        };
+        for part in 1..=day.parts.len() {
+            if args.part.is_some_and(|p| p != part) {
+                continue;
+            }
+            // examples are often only for one of the parts
+            if args.smoke && !expected.iter().any(|e| e.file == file && e.part == part) {
+                continue;
+            }
+            let prefix = match args.smoke {
+                true => format!("Day {} part {} ({file})", day.day, part),
+                false => format!("Day {} part {}", day.day, part),
+            };
+            match verify::check(run_part(day, part, &input), &expected, &file) {
+                Verdict::Match(answer) => {
+                    n_ok += 1;
+                    println!("{prefix}: ok ({answer})");
+                }
+                Verdict::Mismatch { expected, got } => {
+                    n_wrong += 1;
+                    println!("{prefix}: MISMATCH (expected {expected}, got {got})");
+                }
+                Verdict::Missing(answer) => {
+                    n_missing += 1;
+                    println!("{prefix}: missing (got {answer}, nothing recorded)");
+                }
+                Verdict::Failed(Failure::Panic(msg)) => {
+                    n_failed += 1;
+                    println!("{prefix}: FAILED ({msg})");
+                }
+                Verdict::Failed(Failure::Solve(e)) => {
+                    n_failed += 1;
+                    println!("{prefix}: FAILED ({e})");
+                }
+                Verdict::Failed(Failure::Parse(e)) => {
+                    n_failed += 1;
+                    println!("{prefix}: FAILED (bad input)");
+                    println!("{}", e.render());
+                }
+            }
+        }
+    }
+    println!("{n_ok} ok, {n_wrong} mismatched, {n_missing} missing, {n_failed} failed");
+    if n_wrong + n_failed > 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn bench(args: &BenchArgs) -> ExitCode {
+    let baseline = match args.baseline.as_deref().map(bench::load_baseline) {
+        None => None,
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(e)) => {
+            eprintln!("error: can't load baseline: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let fmt_stats = |s: &Stats| {
+        format!(
+            "{} / {} / {}",
+            fmt_duration(s.min),
+            fmt_duration(s.median),
+            fmt_duration(s.mean)
+        )
+    };
+    println!(
+        "{:<12}{:<32}{:<32}{}",
+        "",
+        "parse (min / median / mean)",
+        "solve (min / median / mean)",
+        if baseline.is_some() {
+            "vs baseline (median)"
+        } else {
+            ""
+        }
+    );
+    let (mut n_failed, mut n_over_budget) = (0, 0);
+    let mut results = Vec::new();
+    for day in args.run.selected_days() {
+        let input = match args.run.input_for(day).read(Path::new(day.dir)) {
+            Ok(input) => input,
+            Err(e) => {
+                n_failed += 1;
+                println!("Day {}: FAILED ({})", day.day, e);
+                continue;
+            }
+        };
+        for part in 1..=day.parts.len() {
+            if args.run.part.is_some_and(|p| p != part) {
+                continue;
+            }
+            let prefix = format!("Day {} p{}", day.day, part);
+            let result = match bench::bench_part(day, part, &input, args.repeats) {
+                Ok(result) => result,
+                Err(failure) => {
+                    n_failed += 1;
+                    match failure {
+                        Failure::Panic(msg) => println!("{prefix:<12}FAILED ({msg})"),
+                        Failure::Solve(e) => println!("{prefix:<12}FAILED ({e})"),
+                        Failure::Parse(e) => {
+                            println!("{prefix:<12}FAILED (bad input)\n{}", e.render())
+                        }
+                    }
+                    continue;
+                }
+            };
+            let comparison = baseline.as_ref().map(|baseline| compare(&result, baseline));
+            println!(
+                "{prefix:<12}{:<32}{:<32}{}",
+                fmt_stats(&result.parse),
+                fmt_stats(&result.solve),
+                comparison.unwrap_or_default()
+            );
+            let took = result.parse.median + result.solve.median;
+            if let Some(budget) = budget(&args.run.config, day.day).filter(|b| took > *b) {
+                n_over_budget += 1;
+                println!(
+                    "{:<12}OVER BUDGET ({} > {})",
+                    "",
+                    fmt_duration(took),
+                    fmt_duration(budget)
+                );
+            }
+            results.push(result);
+        }
+    }
+    if let Some(name) = &args.save {
+        match bench::save_baseline(name, &results) {
+            Ok(path) => println!("Saved baseline {name:?} to {}", path.display()),
+            Err(e) => {
+                eprintln!("error: can't save baseline: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if n_failed > 0 {
+        eprintln!("{n_failed} part(s) failed");
+    }
+    if n_over_budget > 0 {
+        eprintln!("{n_over_budget} part(s) took longer than their budget");
+    }
+    if n_failed + n_over_budget > 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// The time budget of each part of `day`. Only release builds are checked
+/// as debug builds can be many times slower.
+fn budget(config: &Config, day: u32) -> Option<Duration> {
+    config.budget(day).filter(|_| !cfg!(debug_assertions))
+}
+
+/// ` (over budget: ...)` if `result` took longer than its day's time budget
+fn over_budget(args: &RunArgs, result: &PartResult) -> Option<String> {
+    let budget = budget(&args.config, result.day)?;
+    let timing = result.timing?;
+    let took = timing.parse + timing.solve;
+    (took > budget).then(|| {
+        format!(
+            " (over budget: {} > {})",
+            fmt_duration(took),
+            fmt_duration(budget)
+        )
+    })
+}
+
+fn compare(result: &BenchResult, baseline: &[BenchResult]) -> String {
+    match baseline
+        .iter()
+        .find(|b| b.day == result.day && b.part == result.part)
+    {
+        Some(b) => format!(
+            "parse {}, solve {}",
+            fmt_change(result.parse.median, b.parse.median),
+            fmt_change(result.solve.median, b.solve.median)
+        ),
+        None => "not in baseline".to_string(),
+    }
+}
+
+fn gen(args: &GenArgs) -> ExitCode {
+    let day = days::get_day(args.day).expect("cli checks that the day exists");
+    let seed = args.seed.unwrap_or_else(|| {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        now.as_nanos() as u64
+    });
+    // on stderr so that the output can be piped straight into `aoc run -`
+    eprintln!(
+        "Day {} input with --seed {seed} --size {}",
+        day.day, args.size
+    );
+    let input = (day.generate)(&mut Rng::new(seed), args.size);
+    match io::stdout().lock().write_all(input.as_bytes()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(args: &NewArgs) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    match scaffold::new_day(root, args.day) {
+        Ok(paths) => {
+            println!("Created day {}:", args.day);
+            for path in paths {
+                println!("  {}", path.strip_prefix(root).unwrap_or(&path).display());
+            }
+            let name = scaffold::crate_name(args.day);
+            println!(
+                "\nNext: paste the example into {name}/src/example.txt and your input into \
+                 {name}/src/input.txt, fill in the TODOs and run `cargo test -p {name}`."
+            );
+            ExitCode::SUCCESS
+        }
+        Err(msg) => {
+            eprintln!("error: {msg}");
+            ExitCode::FAILURE
+        }
+    }
 }
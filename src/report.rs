@@ -0,0 +1,166 @@
+//! Machine-readable output of `aoc run --format json`: one JSON object per
+//! line for each part that was run.
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::run::{Failure, PartResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    /// One [`Record`] per line (JSON Lines)
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: usize,
+    /// `None` if the part failed
+    pub answer: Option<String>,
+    /// Time taken to parse the input and solve the part (`None` if it failed)
+    pub duration_ns: Option<u64>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    /// `None` when the input was read from stdin
+    pub input: Option<String>,
+    pub error: Option<RecordError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecordError {
+    /// `"input"` (couldn't read it), `"parse"` (bad input), `"solve"` (no answer for
+    /// that input) or `"panic"`
+    pub kind: &'static str,
+    pub message: String,
+    /// Where in the input a parse error is (1-based, `None` if it wasn't located)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+impl Record {
+    pub fn new(result: &PartResult, input: Option<&Path>) -> Self {
+        let nanos = |d: std::time::Duration| d.as_nanos() as u64;
+        let error = result.outcome.as_ref().err().map(|failure| match failure {
+            Failure::Parse(e) => {
+                // line 0 means the error was never located in the input
+                let located = e.line != 0;
+                RecordError {
+                    kind: "parse",
+                    message: e.to_string(),
+                    line: located.then_some(e.line),
+                    column: located.then_some(e.column),
+                }
+            }
+            Failure::Solve(e) => RecordError {
+                kind: "solve",
+                message: e.to_string(),
+                line: None,
+                column: None,
+            },
+            Failure::Panic(msg) => RecordError {
+                kind: "panic",
+                message: msg.clone(),
+                line: None,
+                column: None,
+            },
+        });
+        Self {
+            day: result.day,
+            part: result.part,
+            answer: result.outcome.as_ref().ok().map(|a| a.to_string()),
+            duration_ns: result.timing.map(|t| nanos(t.parse + t.solve)),
+            parse_ns: result.timing.map(|t| nanos(t.parse)),
+            solve_ns: result.timing.map(|t| nanos(t.solve)),
+            input: input.map(|p| p.display().to_string()),
+            error,
+        }
+    }
+
+    /// Record for a part that couldn't be run because its input couldn't be read
+    pub fn input_error(day: u32, part: usize, input: Option<&Path>, message: String) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            duration_ns: None,
+            parse_ns: None,
+            solve_ns: None,
+            input: input.map(|p| p.display().to_string()),
+            error: Some(RecordError {
+                kind: "input",
+                message,
+                line: None,
+                column: None,
+            }),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Record should always be serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_common::ParseError;
+
+    use super::*;
+    use crate::days::Timing;
+
+    #[test]
+    fn answer_record() {
+        let result = PartResult {
+            day: 7,
+            part: 2,
+            outcome: Ok(5905_u32.into()),
+            timing: Some(Timing {
+                parse: Duration::from_nanos(300),
+                solve: Duration::from_nanos(1200),
+            }),
+        };
+        assert_eq!(
+            Record::new(&result, Some(Path::new("problem_7/src/input.txt"))).to_json(),
+            r#"{"day":7,"part":2,"answer":"5905","duration_ns":1500,"parse_ns":300,"solve_ns":1200,"input":"problem_7/src/input.txt","error":null}"#
+        );
+    }
+
+    #[test]
+    fn error_records() {
+        let input = "Game 1: 3 red\nGame 2: 4 purple\n";
+        let e = ParseError::new(&input[24..30], "a color").locate(input);
+        let result = PartResult {
+            day: 2,
+            part: 1,
+            outcome: Err(Failure::Parse(e)),
+            timing: None,
+        };
+        let record = Record::new(&result, None);
+        assert_eq!(record.answer, None);
+        assert_eq!(record.duration_ns, None);
+        assert_eq!(
+            serde_json::to_string(&record.error).unwrap(),
+            r#"{"kind":"parse","message":"line 2, column 11: expected a color, found \"purple\"","line":2,"column":11}"#
+        );
+        let unlocated = PartResult {
+            outcome: Err(Failure::Parse(ParseError::new("purple", "a color"))),
+            ..result
+        };
+        let record = Record::new(&unlocated, None);
+        assert_eq!(
+            serde_json::to_string(&record.error).unwrap(),
+            r#"{"kind":"parse","message":"expected a color, found \"purple\""}"#
+        );
+        let record = Record::input_error(3, 1, None, "no such file".into());
+        assert!(record
+            .to_json()
+            .ends_with(r#""input":null,"error":{"kind":"input","message":"no such file"}}"#));
+    }
+}
@@ -0,0 +1,132 @@
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use aoc_common::{Answer, ParseError, SolveError};
+
+use crate::days::{Day, Timing};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The input isn't in the format the day expects
+    Parse(ParseError),
+    /// The input is well-formed but has no answer
+    Solve(SolveError),
+    /// The solution panicked with this message
+    Panic(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: usize,
+    pub outcome: Result<Answer, Failure>,
+    /// How long it took (only if it succeeded)
+    pub timing: Option<Timing>,
+}
+
+/// Run part `part` (1-based) of `day` on `input`, catching any panic so that
+/// one broken solution doesn't stop the rest of them from running.
+pub fn run_part(day: &Day, part: usize, input: &str) -> PartResult {
+    let f = day.parts[part - 1];
+    let (outcome, timing) = match panic::catch_unwind(|| f(input)) {
+        Ok(Ok((answer, timing))) => (Ok(answer), Some(timing)),
+        Ok(Err(failure)) => (Err(failure), None),
+        Err(payload) => {
+            let msg = if let Some(s) = payload.downcast_ref::<&str>() {
+                s.to_string()
+            } else if let Some(s) = payload.downcast_ref::<String>() {
+                s.clone()
+            } else {
+                "<unknown panic payload>".to_string()
+            };
+            (Err(Failure::Panic(msg)), None)
+        }
+    };
+    PartResult {
+        day: day.day,
+        part,
+        outcome,
+        timing,
+    }
+}
+
+/// One part of a day for [`run_parallel`] to run
+#[derive(Debug, Clone, Copy)]
+pub struct Job<'a> {
+    pub day: &'a Day,
+    pub part: usize,
+    pub input: &'a str,
+}
+
+/// Run `jobs` on a pool of (at most) `threads` threads. Each job is isolated
+/// like in [`run_part`], so a panic only fails that one part.
+/// The results are in the same order as `jobs`.
+pub fn run_parallel(jobs: &[Job], threads: usize) -> Vec<PartResult> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; jobs.len()]);
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            s.spawn(|| {
+                // take the next job that hasn't been started yet
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(job) = jobs.get(i) else { break };
+                    let result = run_part(job.day, job.part, job.input);
+                    results.lock().unwrap()[i] = Some(result);
+                }
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("Every job should have been run"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{PartFn, Timing};
+
+    const PARTS: &[PartFn] = &[
+        |input| Ok((input.len().into(), Timing::default())),
+        |_| panic!("part 2 is broken"),
+    ];
+    const DAY: Day = Day {
+        day: 99,
+        dir: "",
+        parts: PARTS,
+        generate: |_, _| String::new(),
+    };
+
+    #[test]
+    fn parallel_results_are_in_order_and_panics_are_isolated() {
+        let inputs = ["a", "bb", "ccc", "dddd"];
+        let jobs: Vec<_> = inputs
+            .iter()
+            .flat_map(|input| {
+                (1..=2).map(|part| Job {
+                    day: &DAY,
+                    part,
+                    input,
+                })
+            })
+            .collect();
+        let results = run_parallel(&jobs, 3);
+        assert_eq!(results.len(), 8);
+        for (job, result) in jobs.iter().zip(&results) {
+            assert_eq!(result.part, job.part);
+            match job.part {
+                1 => assert_eq!(result.outcome, Ok(job.input.len().into())),
+                _ => assert_eq!(
+                    result.outcome,
+                    Err(Failure::Panic("part 2 is broken".into()))
+                ),
+            }
+        }
+    }
+}
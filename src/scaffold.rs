@@ -0,0 +1,205 @@
+//! `aoc new`: creates the crate for a new day from the templates in
+//! `src/templates/` and registers it with the runner and the fuzzer.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::days::DAYS;
+
+const LIB_TEMPLATE: &str = include_str!("templates/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("templates/main.rs.template");
+const MANIFEST_TEMPLATE: &str = include_str!("templates/Cargo.toml.template");
+const ANSWERS_TEMPLATE: &str = include_str!("templates/answers.txt.template");
+const FUZZ_TARGET_TEMPLATE: &str = include_str!("templates/fuzz_target.rs.template");
+
+/// The day after the last one that has been solved
+pub fn next_day() -> u32 {
+    DAYS.iter().map(|d| d.day).max().map_or(1, |d| d + 1)
+}
+
+pub fn crate_name(day: u32) -> String {
+    format!("problem_{day}")
+}
+
+fn fill_in(template: &str, day: u32) -> String {
+    template
+        .replace("CRATE_NAME", &crate_name(day))
+        .replace("DAY_NUM", &day.to_string())
+}
+
+/// Insert `new_line` for `day` among the lines of `text` that start with
+/// `prefix` followed by a day number, keeping them in order of day: before
+/// the first one for a later day, or else after the last one
+fn insert_in_order(text: &str, prefix: &str, day: u32, new_line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entry_day = |ln: &str| -> Option<u32> {
+        let rest = ln.strip_prefix(prefix)?;
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        rest[..end].parse().ok()
+    };
+    let last = lines.iter().rposition(|ln| entry_day(ln).is_some())?;
+    let i = lines
+        .iter()
+        .position(|ln| entry_day(ln).is_some_and(|d| d > day))
+        .unwrap_or(last + 1);
+    lines.insert(i, new_line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add the day's crate to the members and dependencies of the root `Cargo.toml`
+fn add_to_manifest(manifest: &str, day: u32) -> Option<String> {
+    let name = crate_name(day);
+    let with_member =
+        insert_in_order(manifest, "    \"problem_", day, &format!("    \"{name}\","))?;
+    insert_in_order(
+        &with_member,
+        "problem_",
+        day,
+        &format!("{name} = {{ path = \"{name}\" }}"),
+    )
+}
+
+/// Add the day to `DAYS` in `src/days.rs` (which is in order of day)
+fn add_to_days(days_rs: &str, day: u32) -> Option<String> {
+    let entry = format!("    Day::of::<{}::Day{day}>(),", crate_name(day));
+    insert_in_order(days_rs, "    Day::of::<problem_", day, &entry)
+}
+
+fn add_fuzz_target(fuzz_manifest: &str, day: u32) -> String {
+    format!(
+        "{}\n[[bin]]\nname = \"day_{day}\"\npath = \"fuzz_targets/day_{day}.rs\"\n\
+         test = false\ndoc = false\nbench = false\n",
+        fuzz_manifest.trim_end()
+    )
+}
+
+/// Create the crate for `day` in the workspace at `root` and register it
+/// everywhere it needs to be, returning the paths of the files created or changed.
+/// Nothing is written if anything is wrong (e.g. the crate already exists).
+pub fn new_day(root: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    if DAYS.iter().any(|d| d.day == day) {
+        return Err(format!("day {day} has already been solved"));
+    }
+    let crate_dir = root.join(crate_name(day));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()));
+    let cant_register =
+        |path: &Path| format!("couldn't find where to add day {day} in {}", path.display());
+
+    let manifest_path = root.join("Cargo.toml");
+    let days_path = root.join("src").join("days.rs");
+    let fuzz_manifest_path = root.join("fuzz").join("Cargo.toml");
+    let src = crate_dir.join("src");
+    let files = [
+        (
+            manifest_path.clone(),
+            add_to_manifest(&read(&manifest_path)?, day)
+                .ok_or_else(|| cant_register(&manifest_path))?,
+        ),
+        (
+            days_path.clone(),
+            add_to_days(&read(&days_path)?, day).ok_or_else(|| cant_register(&days_path))?,
+        ),
+        (
+            fuzz_manifest_path.clone(),
+            add_fuzz_target(&read(&fuzz_manifest_path)?, day),
+        ),
+        (
+            root.join("fuzz")
+                .join("fuzz_targets")
+                .join(format!("day_{day}.rs")),
+            fill_in(FUZZ_TARGET_TEMPLATE, day),
+        ),
+        (
+            crate_dir.join("Cargo.toml"),
+            fill_in(MANIFEST_TEMPLATE, day),
+        ),
+        (src.join("lib.rs"), fill_in(LIB_TEMPLATE, day)),
+        (src.join("main.rs"), fill_in(MAIN_TEMPLATE, day)),
+        (src.join("answers.txt"), ANSWERS_TEMPLATE.to_string()),
+        (src.join("example.txt"), String::new()),
+        (src.join("input.txt"), String::new()),
+    ];
+    fs::create_dir_all(&src).map_err(|e| format!("{}: {e}", src.display()))?;
+    for (path, contents) in &files {
+        fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "\
+[dependencies]
+aoc_common.workspace = true
+problem_1 = { path = \"problem_1\" }
+problem_2 = { path = \"problem_2\" }
+serde.workspace = true
+
+[workspace]
+members = [
+    \"aoc_common\",
+    \"problem_1\",
+    \"problem_2\",
+]
+";
+
+    #[test]
+    fn registers_crate_in_manifest() {
+        let manifest = add_to_manifest(MANIFEST, 3).unwrap();
+        assert!(manifest.contains(
+            "problem_2 = { path = \"problem_2\" }\nproblem_3 = { path = \"problem_3\" }\nserde"
+        ));
+        assert!(manifest.contains("    \"problem_2\",\n    \"problem_3\",\n]"));
+        assert_eq!(add_to_manifest("[package]\n", 3), None);
+    }
+
+    #[test]
+    fn registers_day_in_days() {
+        let days_rs = "pub const DAYS: &[Day] = &[\n    Day::of::<problem_1::Day1>(),\n];\n";
+        assert_eq!(
+            add_to_days(days_rs, 2).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    Day::of::<problem_1::Day1>(),\n    \
+             Day::of::<problem_2::Day2>(),\n];\n"
+        );
+    }
+
+    #[test]
+    fn registers_days_out_of_order() {
+        let manifest = add_to_manifest(&add_to_manifest(MANIFEST, 20).unwrap(), 15).unwrap();
+        assert!(manifest.contains(
+            "problem_2 = { path = \"problem_2\" }\nproblem_15 = { path = \"problem_15\" }\n\
+             problem_20 = { path = \"problem_20\" }\nserde"
+        ));
+        assert!(manifest.contains("    \"problem_15\",\n    \"problem_20\",\n]"));
+
+        let days_rs = "pub const DAYS: &[Day] = &[\n    Day::of::<problem_1::Day1>(),\n];\n";
+        let days_rs = add_to_days(&add_to_days(days_rs, 20).unwrap(), 15).unwrap();
+        assert_eq!(
+            days_rs,
+            "pub const DAYS: &[Day] = &[\n    Day::of::<problem_1::Day1>(),\n    \
+             Day::of::<problem_15::Day15>(),\n    Day::of::<problem_20::Day20>(),\n];\n"
+        );
+    }
+
+    #[test]
+    fn templates_are_filled_in() {
+        let main = fill_in(MAIN_TEMPLATE, 15);
+        assert!(main.contains("run_main::<problem_15::Day15>()"));
+        let lib = fill_in(LIB_TEMPLATE, 15);
+        assert!(lib.contains("pub struct Day15;") && lib.contains("const DAY: u32 = 15;"));
+        assert!(lib.contains("parse_input::<Day15>(include_str!(\"example.txt\"))"));
+        assert!(!lib.contains("DAY_NUM") && !main.contains("CRATE_NAME"));
+    }
+
+    #[test]
+    fn next_day_is_after_the_last() {
+        assert_eq!(next_day(), DAYS.last().unwrap().day + 1);
+    }
+}
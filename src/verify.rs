@@ -0,0 +1,91 @@
+use aoc_common::Answer;
+
+use crate::answers::Expected;
+use crate::run::{Failure, PartResult};
+
+/// Result of checking one part against the recorded answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match(Answer),
+    Mismatch {
+        expected: Answer,
+        got: Answer,
+    },
+    /// There is no recorded answer for this part
+    Missing(Answer),
+    Failed(Failure),
+}
+
+/// Check `result` (from running the day on `file`) against the recorded
+/// answers. Numbers are compared by value, see [`Answer`].
+pub fn check(result: PartResult, answers: &[Expected], file: &str) -> Verdict {
+    let got = match result.outcome {
+        Ok(got) => got,
+        Err(failure) => return Verdict::Failed(failure),
+    };
+    let expected = answers
+        .iter()
+        .find(|exp| exp.file == file && exp.part == result.part);
+    match expected {
+        None => Verdict::Missing(got),
+        Some(exp) if exp.answer == got => Verdict::Match(got),
+        Some(exp) => Verdict::Mismatch {
+            expected: exp.answer.clone(),
+            got,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers;
+
+    fn result(part: usize, outcome: Result<i64, Failure>) -> PartResult {
+        PartResult {
+            day: 1,
+            part,
+            outcome: outcome.map(Answer::from),
+            timing: None,
+        }
+    }
+
+    #[test]
+    fn verdicts() {
+        let answers = answers::parse("input.txt 1 42\nexample.txt 2 7\n").unwrap();
+        assert_eq!(
+            check(result(1, Ok(42)), &answers, "input.txt"),
+            Verdict::Match(42.into())
+        );
+        assert_eq!(
+            check(result(1, Ok(43)), &answers, "input.txt"),
+            Verdict::Mismatch {
+                expected: 42.into(),
+                got: 43.into()
+            }
+        );
+        assert_eq!(
+            check(result(2, Ok(7)), &answers, "input.txt"),
+            Verdict::Missing(7.into())
+        );
+        let failure = Failure::Panic("oops".into());
+        assert_eq!(
+            check(result(1, Err(failure.clone())), &answers, "input.txt"),
+            Verdict::Failed(failure)
+        );
+    }
+
+    #[test]
+    fn numbers_match_by_value() {
+        // the recorded answers are read as big integers, the results here are i64s
+        let answers = answers::parse("input.txt 1 0042\ninput.txt 2 -0\n").unwrap();
+        assert_eq!(
+            check(result(1, Ok(42)), &answers, "input.txt"),
+            Verdict::Match(42.into())
+        );
+        assert_eq!(
+            check(result(2, Ok(0)), &answers, "input.txt"),
+            Verdict::Match(0.into())
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! `aoc run --watch`: runs the selected days again whenever their code or
+//! input changes and shows how the answers and timings changed.
+//!
+//! Changed code has to be rebuilt, so each run is done by `cargo run` in a
+//! child process (with `--format json`) instead of in this one. The child is
+//! built in its own target directory so that it never overwrites this binary
+//! while it runs (which Windows doesn't allow at all).
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_common::input::InputSource;
+use serde::Deserialize;
+
+use crate::bench::{fmt_change, fmt_duration};
+use crate::cli::RunArgs;
+use crate::config;
+use crate::days::Day;
+
+/// How often the files are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification time of each watched file (`None` if it can't be read, e.g. it was deleted)
+pub type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// The files that can change the answers of `day`: the manifests and
+/// everything in the `src` directories of the day's crate (code, examples and
+/// the input) and of `aoc_common` (which every day uses), the input file and
+/// the runner's config
+pub fn watched_files(day: &Day, input: &InputSource) -> Vec<PathBuf> {
+    let dir = Path::new(day.dir);
+    let common = Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc_common");
+    let mut files = vec![
+        dir.join("Cargo.toml"),
+        common.join("Cargo.toml"),
+        PathBuf::from(config::PATH),
+    ];
+    add_files_in(&dir.join("src"), &mut files);
+    add_files_in(&common.join("src"), &mut files);
+    if let Ok(Some(path)) = input.path(dir) {
+        if !files.contains(&path) {
+            files.push(path);
+        }
+    }
+    files
+}
+
+fn add_files_in(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.filter_map(|e| Some(e.ok()?.path())) {
+        if path.is_dir() {
+            add_files_in(&path, files);
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext == "rs" || ext == "txt")
+        {
+            files.push(path);
+        }
+    }
+}
+
+pub fn snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// The files that were added, removed or modified between two snapshots
+pub fn changed_files(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<_> = old
+        .keys()
+        .chain(new.keys())
+        .filter(|path| old.get(*path) != new.get(*path))
+        .cloned()
+        .collect();
+    changed.sort();
+    changed.dedup();
+    changed
+}
+
+/// The parts of one line of `aoc run --format json` output (a
+/// [`crate::report::Record`]) that watch mode shows
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Outcome {
+    pub day: u32,
+    pub part: usize,
+    pub answer: Option<String>,
+    pub duration_ns: Option<u64>,
+    pub error: Option<OutcomeError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct OutcomeError {
+    pub message: String,
+}
+
+impl Outcome {
+    fn result(&self) -> String {
+        match (&self.answer, &self.error) {
+            (Some(answer), _) => answer.clone(),
+            (None, Some(e)) => format!("FAILED ({})", e.message),
+            (None, None) => "FAILED".to_string(),
+        }
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        self.duration_ns.map(Duration::from_nanos)
+    }
+}
+
+/// One line describing `new`, and how it differs from `old` (the previous
+/// run of the same part) if there was one
+pub fn describe(new: &Outcome, old: Option<&Outcome>) -> String {
+    let prefix = format!("Day {} part {}", new.day, new.part);
+    let now = new.result();
+    let Some(old) = old else {
+        let time = new.duration().map(|t| format!(" in {}", fmt_duration(t)));
+        return format!("{prefix}: {now}{}", time.unwrap_or_default());
+    };
+    let answer = if old.result() == now {
+        format!("{now} (unchanged)")
+    } else {
+        format!("{} -> {now}", old.result())
+    };
+    let time = match (old.duration(), new.duration()) {
+        (Some(before), Some(after)) => format!(
+            ", {} -> {} ({})",
+            fmt_duration(before),
+            fmt_duration(after),
+            fmt_change(after, before)
+        ),
+        (None, Some(after)) => format!(" in {}", fmt_duration(after)),
+        (_, None) => String::new(),
+    };
+    format!("{prefix}: {answer}{time}")
+}
+
+/// Arguments for `cargo` to run `days` like `args` does (with the same build
+/// profile as this binary), printing JSON. It is built in `target/watch` next
+/// to `manifest`, away from this binary.
+pub fn child_args(args: &RunArgs, manifest: &Path, days: &[u32]) -> Vec<String> {
+    let mut out: Vec<String> = ["run", "--quiet", "--manifest-path"]
+        .map(String::from)
+        .into();
+    out.push(manifest.display().to_string());
+    let target_dir = manifest.with_file_name("target").join("watch");
+    out.extend(["--target-dir".into(), target_dir.display().to_string()]);
+    if !cfg!(debug_assertions) {
+        out.push("--release".into());
+    }
+    out.extend(["--bin", "aoc", "--", "run", "--format", "json"].map(String::from));
+    for day in days {
+        out.extend(["--day".into(), day.to_string()]);
+    }
+    if let Some(part) = args.part {
+        out.extend(["--part".into(), part.to_string()]);
+    }
+    match &args.input {
+        InputSource::Default => {}
+        InputSource::File(path) => out.extend(["--input".into(), path.display().to_string()]),
+        InputSource::Example(name) => out.extend(["--example".into(), name.clone()]),
+        InputSource::Stdin => unreachable!("cli doesn't allow --watch with stdin"),
+    }
+    // the child starts from the verbosity in the config too
+    let extra_verbosity = args.verbosity.saturating_sub(args.config.verbosity);
+    out.extend((0..extra_verbosity).map(|_| "-v".to_string()));
+    out
+}
+
+/// Build and run `days` in a child process. `Ok(None)` if it couldn't be
+/// built (cargo will have printed why).
+fn run_child(args: &RunArgs, days: &[u32]) -> Result<Option<Vec<Outcome>>, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let output = Command::new(&cargo)
+        .args(child_args(args, &manifest, days))
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("can't run {cargo}: {e}"))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let outcomes: Vec<Outcome> = stdout
+        .lines()
+        .filter_map(|ln| serde_json::from_str(ln).ok())
+        .collect();
+    if outcomes.is_empty() && !output.status.success() {
+        return Ok(None);
+    }
+    Ok(Some(outcomes))
+}
+
+/// Run the selected days, then again every time their files change. Only
+/// returns if the child process can't be started.
+pub fn watch(args: &RunArgs) -> ExitCode {
+    let days = args.selected_days();
+    let files = |day: &Day| watched_files(day, &args.input_for(day));
+    let mut snapshots: Vec<_> = days.iter().map(|day| snapshot(&files(day))).collect();
+    let mut previous: BTreeMap<(u32, usize), Outcome> = BTreeMap::new();
+    let mut to_run: Vec<u32> = days.iter().map(|day| day.day).collect();
+    let n_files: usize = snapshots.iter().map(|s| s.len()).sum();
+    println!("Watching {n_files} file(s), press Ctrl-C to stop");
+    loop {
+        if !to_run.is_empty() {
+            match run_child(args, &to_run) {
+                Ok(Some(outcomes)) => {
+                    for outcome in outcomes {
+                        let key = (outcome.day, outcome.part);
+                        println!("{}", describe(&outcome, previous.get(&key)));
+                        previous.insert(key, outcome);
+                    }
+                }
+                Ok(None) => println!("Build failed, waiting for changes"),
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+        to_run.clear();
+        for (day, old) in days.iter().zip(&mut snapshots) {
+            let new = snapshot(&files(day));
+            let changed = changed_files(old, &new);
+            if changed.is_empty() {
+                continue;
+            }
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            for path in changed {
+                let path = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+                println!("\n{} changed", path.display());
+            }
+            to_run.push(day.day);
+            *old = new;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(answer: Option<&str>, duration_ns: Option<u64>) -> Outcome {
+        Outcome {
+            day: 5,
+            part: 2,
+            answer: answer.map(String::from),
+            duration_ns,
+            error: answer.is_none().then(|| OutcomeError {
+                message: "the answer is too big".into(),
+            }),
+        }
+    }
+
+    #[test]
+    fn changes_between_snapshots() {
+        let t = |s| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(s));
+        let old = Snapshot::from([("a.rs".into(), t(1)), ("b.rs".into(), t(1))]);
+        let new = Snapshot::from([
+            ("a.rs".into(), t(1)),
+            ("b.rs".into(), t(2)),
+            ("c.txt".into(), t(2)),
+        ]);
+        assert_eq!(
+            changed_files(&old, &new),
+            [PathBuf::from("b.rs"), PathBuf::from("c.txt")]
+        );
+        assert_eq!(changed_files(&new, &old).len(), 2);
+        assert!(changed_files(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn describe_changes() {
+        let first = outcome(Some("46"), Some(2_000_000));
+        assert_eq!(describe(&first, None), "Day 5 part 2: 46 in 2.0ms");
+        let faster = outcome(Some("46"), Some(1_000_000));
+        assert_eq!(
+            describe(&faster, Some(&first)),
+            "Day 5 part 2: 46 (unchanged), 2.0ms -> 1.0ms (-50.0%)"
+        );
+        let failed = outcome(None, None);
+        assert_eq!(
+            describe(&failed, Some(&first)),
+            "Day 5 part 2: 46 -> FAILED (the answer is too big)"
+        );
+        assert_eq!(
+            describe(&first, Some(&failed)),
+            "Day 5 part 2: FAILED (the answer is too big) -> 46 in 2.0ms"
+        );
+    }
+
+    #[test]
+    fn watches_the_day_and_aoc_common() {
+        let day = crate::days::get_day(3).unwrap();
+        let files = watched_files(day, &InputSource::Default);
+        let common = Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc_common");
+        for file in [
+            Path::new(day.dir).join("src/lib.rs"),
+            common.join("Cargo.toml"),
+            common.join("src/grid.rs"),
+        ] {
+            assert!(files.contains(&file), "{} isn't watched", file.display());
+        }
+    }
+
+    #[test]
+    fn reads_json_records() {
+        let line = r#"{"day":5,"part":2,"answer":"46","duration_ns":2000000,"parse_ns":1,"solve_ns":1,"input":null,"error":null}"#;
+        let parsed: Outcome = serde_json::from_str(line).unwrap();
+        assert_eq!(parsed, outcome(Some("46"), Some(2_000_000)));
+    }
+
+    #[test]
+    fn child_runs_the_same_parts() {
+        let args = RunArgs {
+            part: Some(1),
+            input: InputSource::Example("2".into()),
+            verbosity: 1,
+            ..RunArgs::default()
+        };
+        let child = child_args(&args, Path::new("/aoc/Cargo.toml"), &[3, 4]);
+        let target_dir = child.iter().position(|a| a == "--target-dir").unwrap() + 1;
+        assert_eq!(
+            Path::new(&child[target_dir]),
+            Path::new("/aoc/target/watch")
+        );
+        let after_sep = child.iter().position(|a| a == "--").unwrap() + 1;
+        assert_eq!(
+            child[after_sep..],
+            [
+                "run",
+                "--format",
+                "json",
+                "--day",
+                "3",
+                "--day",
+                "4",
+                "--part",
+                "1",
+                "--example",
+                "2",
+                "-v"
+            ]
+        );
+    }
+}
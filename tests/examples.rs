@@ -0,0 +1,117 @@
+//! Runs every day on the example inputs listed in its `src/answers.txt`
+//! and checks that it gives the expected answers.
+use std::fs;
+use std::path::Path;
+
+use aoc::answers;
+use aoc::days::DAYS;
+use aoc::run::{run_part, Failure};
+use aoc_common::input::list_examples;
+
+#[test]
+fn examples_give_expected_answers() {
+    let mut n_checked = 0;
+    let mut failures = Vec::new();
+    for day in DAYS {
+        let expected = answers::load(day).unwrap_or_else(|e| panic!("{e}"));
+        for exp in expected
+            .iter()
+            .filter(|exp| exp.file.starts_with("example"))
+        {
+            n_checked += 1;
+            let what = format!("day {} {} part {}", day.day, exp.file, exp.part);
+            if exp.part > day.parts.len() {
+                failures.push(format!("{what}: part {} isn't implemented", exp.part));
+                continue;
+            }
+            let path = Path::new(day.dir).join("src").join(&exp.file);
+            let input = match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(e) => {
+                    failures.push(format!("{what}: can't read {}: {e}", path.display()));
+                    continue;
+                }
+            };
+            match run_part(day, exp.part, &input).outcome {
+                Ok(answer) if answer == exp.answer => {}
+                Ok(answer) => {
+                    failures.push(format!("{what}: expected {}, got {answer}", exp.answer))
+                }
+                Err(Failure::Panic(msg)) => failures.push(format!("{what}: panicked ({msg})")),
+                Err(Failure::Parse(e)) => failures.push(format!("{what}: {}", e.render())),
+                Err(Failure::Solve(e)) => failures.push(format!("{what}: no answer ({e})")),
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} of {n_checked} example answers are wrong:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/// The examples as if they had been saved on Windows, by an editor that
+/// adds a byte order mark and leaves whitespace at the ends of lines
+#[test]
+fn examples_give_the_same_answers_with_messy_whitespace() {
+    for day in DAYS {
+        let expected = answers::load(day).unwrap_or_else(|e| panic!("{e}"));
+        for exp in expected
+            .iter()
+            .filter(|exp| exp.file.starts_with("example") && exp.part <= day.parts.len())
+        {
+            let path = Path::new(day.dir).join("src").join(&exp.file);
+            let input = fs::read_to_string(&path).unwrap();
+            let messy = format!(
+                "\u{feff}{}\r\n\r\n",
+                input.lines().collect::<Vec<_>>().join(" \t\r\n")
+            );
+            let answer = run_part(day, exp.part, &messy).outcome;
+            assert_eq!(
+                answer,
+                Ok(exp.answer.clone()),
+                "day {} {} part {}",
+                day.day,
+                exp.file,
+                exp.part
+            );
+        }
+    }
+}
+
+/// Parse errors give the line and column in the file as it was given, not
+/// in the normalized copy that the day parsed
+#[test]
+fn parse_errors_point_at_the_original_lines() {
+    let day = DAYS.iter().find(|d| d.day == 2).unwrap();
+    let outcome = run_part(day, 1, "\n\nGame 1: 3 blue\n\nGame 2: 4 purple\n").outcome;
+    let Err(Failure::Parse(e)) = outcome else {
+        panic!("expected a parse error, got {outcome:?}");
+    };
+    assert_eq!((e.line, e.column), (5, 11));
+    assert_eq!(e.line_text, "Game 2: 4 purple");
+
+    let day = DAYS.iter().find(|d| d.day == 13).unwrap();
+    let outcome = run_part(day, 1, "\u{feff}#.\r\n\r\n \r\n.x\r\n").outcome;
+    let Err(Failure::Parse(e)) = outcome else {
+        panic!("expected a parse error, got {outcome:?}");
+    };
+    assert_eq!((e.line, e.column), (4, 2));
+}
+
+#[test]
+fn every_example_has_an_answer() {
+    for day in DAYS {
+        let expected = answers::load(day).unwrap_or_else(|e| panic!("{e}"));
+        for name in list_examples(Path::new(day.dir)) {
+            let file = format!("{name}.txt");
+            assert!(
+                expected.iter().any(|exp| exp.file == file),
+                "day {}: {file} isn't listed in {}",
+                day.day,
+                answers::path(day).display()
+            );
+        }
+    }
+}
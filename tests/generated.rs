@@ -0,0 +1,34 @@
+//! Runs every day on inputs from its random generator: they should always
+//! parse and be solvable.
+use aoc::days::DAYS;
+use aoc::run::run_part;
+use aoc_common::Rng;
+
+#[test]
+fn generated_inputs_are_solvable() {
+    let mut failures = Vec::new();
+    for day in DAYS {
+        for seed in 0..4 {
+            let input = (day.generate)(&mut Rng::new(seed), 20);
+            for part in 1..=day.parts.len() {
+                let result = run_part(day, part, &input);
+                if let Err(e) = result.outcome {
+                    failures.push(format!(
+                        "day {} part {part} (seed {seed}): {e:?}\n{input}",
+                        day.day
+                    ));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn generators_are_deterministic() {
+    for day in DAYS {
+        let a = (day.generate)(&mut Rng::new(7), 10);
+        let b = (day.generate)(&mut Rng::new(7), 10);
+        assert_eq!(a, b, "day {}", day.day);
+    }
+}
@@ -0,0 +1,165 @@
+//! Feeds every day mutated versions of its examples and generated inputs:
+//! whatever the input, each part should give an answer or an error, never
+//! panic or hang. (The `fuzz` directory does the same with cargo-fuzz.)
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use aoc::days::{Day, DAYS};
+use aoc::run::{run_part, Failure};
+use aoc_common::input::{example_path, list_examples};
+use aoc_common::Rng;
+
+const N_MUTATIONS: usize = 300;
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+fn seed_inputs(day: &Day) -> Vec<String> {
+    let dir = std::path::Path::new(day.dir);
+    let mut inputs: Vec<_> = list_examples(dir)
+        .iter()
+        .filter_map(|name| std::fs::read_to_string(example_path(dir, name)?).ok())
+        .collect();
+    inputs.push((day.generate)(&mut Rng::new(0), 5));
+    inputs
+}
+
+/// Change `input` a bit, in one of the ways that real (or malicious) inputs go wrong
+fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    let mut lines: Vec<&str> = input.lines().collect();
+    let alphabet: Vec<char> = if chars.is_empty() {
+        vec!['x']
+    } else {
+        chars.clone()
+    };
+    match rng.below(8) {
+        0 if !chars.is_empty() => {
+            let i = rng.index(chars.len());
+            chars[i] = *rng.choose(&alphabet);
+        }
+        1 if !chars.is_empty() => {
+            let i = rng.index(chars.len());
+            let end = (i + rng.index(20)).min(chars.len());
+            chars.drain(i..end);
+        }
+        2 => {
+            let i = rng.index(chars.len() + 1);
+            chars.insert(i, *rng.choose(&alphabet));
+        }
+        3 => {
+            let i = rng.index(chars.len() + 1);
+            let big = *rng.choose(&[
+                "0",
+                "99999999999999999999",
+                "4294967295",
+                "18446744073709551615",
+                "-1",
+            ]);
+            chars.splice(i..i, big.chars());
+        }
+        4 if !lines.is_empty() => {
+            let i = rng.index(lines.len());
+            lines.insert(rng.index(lines.len() + 1), lines[i]);
+            return lines.join("\n");
+        }
+        5 if !lines.is_empty() => {
+            lines.remove(rng.index(lines.len()));
+            return lines.join("\n");
+        }
+        6 if !lines.is_empty() => {
+            let i = rng.index(lines.len());
+            lines[i] = "";
+            return lines.join("\n");
+        }
+        _ => chars.truncate(rng.index(chars.len() + 1)),
+    }
+    chars.into_iter().collect()
+}
+
+/// Run every part of `day` on `input`, returning a description of the problem if one panics or hangs
+fn check(day: &'static Day, input: String) -> Result<(), String> {
+    let (tx, rx) = mpsc::channel();
+    let input_copy = input.clone();
+    thread::spawn(move || {
+        let panics: Vec<_> = (1..=day.parts.len())
+            .filter_map(|part| match run_part(day, part, &input_copy).outcome {
+                Err(Failure::Panic(msg)) => Some(format!("part {part} panicked: {msg}")),
+                _ => None,
+            })
+            .collect();
+        let _ = tx.send(panics);
+    });
+    match rx.recv_timeout(TIMEOUT) {
+        Ok(panics) if panics.is_empty() => Ok(()),
+        Ok(panics) => Err(format!(
+            "day {}: {}\ninput:\n{input}",
+            day.day,
+            panics.join(", ")
+        )),
+        Err(_) => Err(format!(
+            "day {}: took over {TIMEOUT:?}\ninput:\n{input}",
+            day.day
+        )),
+    }
+}
+
+#[test]
+fn mutated_inputs_dont_panic_or_hang() {
+    let mut failures = Vec::new();
+    for day in DAYS {
+        let mut rng = Rng::new(day.day as u64);
+        let seeds = seed_inputs(day);
+        for _ in 0..N_MUTATIONS {
+            let mut input = rng.choose(&seeds).clone();
+            for _ in 0..rng.range(1..4) {
+                input = mutate(&mut rng, &input);
+            }
+            if let Err(e) = check(day, input) {
+                failures.push(e);
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}
+
+/// Inputs that found panics, hangs or wrong answers, which random mutations
+/// of the examples are unlikely to hit
+fn nasty_inputs() -> Vec<(u32, String)> {
+    let matching: Vec<_> = (1..=40).map(|n| n.to_string()).collect();
+    vec![
+        (1, "a²b\n".to_string()),
+        (4, format!("Card 1: {0} | {0}\n", matching.join(" "))),
+        (6, "Time: 18446744073709551615\nDistance: 0\n".to_string()),
+        (6, "Time: 2\nDistance: 1\n".to_string()),
+        (8, "L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n".to_string()),
+        (
+            9,
+            "9223372036854775807 -9223372036854775808 0\n".to_string(),
+        ),
+        // recursed once per group (and took forever) before
+        (
+            12,
+            format!("{} {}\n", "?".repeat(500), vec!["1"; 250].join(",")),
+        ),
+        (12, format!("{} 1\n", "?".repeat(1000))),
+    ]
+}
+
+#[test]
+fn nasty_inputs_dont_panic_or_hang() {
+    let failures: Vec<_> = nasty_inputs()
+        .into_iter()
+        .filter_map(|(day, input)| check(aoc::days::get_day(day).unwrap(), input).err())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}